@@ -0,0 +1,349 @@
+
+//! Derive macros for `nsdq-util`.
+//! Use them through the re-exports in `nsdq_util`,
+//! which also documents them with examples.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input,
+    spanned::Spanned,
+    Attribute,
+    Data,
+    DeriveInput,
+    Fields,
+    LitByte,
+    LitChar,
+};
+
+
+/// Generate `LEN`, `parse`, `encode`, and a `Wire` implementation
+/// for a struct with named fields, encoded in declaration order.
+///
+/// Struct attributes:
+/// - `#[tag(b'A')]` prefixes the message with a type byte,
+///   exposed as `TAG` and checked when parsing.
+///
+/// Field attributes:
+/// - `#[bool(yes = 'R', no = 'X')]` for a `bool` with a custom mapping.
+/// - `#[ternary(yes = 'Y', no = 'N', uncertain = ' ')]` for an `Option<bool>`;
+///   any character left out uses the standard mapping.
+/// - `#[timestamp(itch)]` or `#[timestamp(ouch)]` for a `Timestamp`,
+///   encoded as 6 or 8 bytes respectively.
+///
+/// Every other field type must implement `Wire`.
+#[proc_macro_derive(NsdqMessage, attributes(tag, bool, ternary, timestamp))]
+pub fn derive_nsdq_message(input: TokenStream) -> TokenStream {
+
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+
+/// How a single field is laid out on the wire.
+enum Layout<'a> {
+    Wire(&'a syn::Type),
+    Bool { yes: char, no: char },
+    Ternary { yes: char, no: char, uncertain: char },
+    ItchTime,
+    OuchTime,
+}
+
+impl<'a> Layout<'a> {
+
+    /// Read the field attributes, defaulting to the `Wire` implementation.
+    fn from_field(field: &'a syn::Field) -> syn::Result<Self> {
+
+        let mut layout = Layout::Wire(&field.ty);
+        for attr in &field.attrs {
+
+            if attr.path().is_ident("bool") {
+                let (mut yes, mut no) = ('Y', 'N');
+                if !matches!(attr.meta, syn::Meta::Path(_)) {
+                    attr.parse_nested_meta(|meta| {
+                        let ch: LitChar = meta.value()?.parse()?;
+                        if meta.path.is_ident("yes") { yes = ch.value() }
+                        else if meta.path.is_ident("no") { no = ch.value() }
+                        else { return Err(meta.error("expected `yes` or `no`")) }
+                        Ok(())
+                    })?;
+                }
+                layout = Layout::Bool { yes, no };
+            }
+
+            else if attr.path().is_ident("ternary") {
+                let (mut yes, mut no, mut uncertain) = ('Y', 'N', ' ');
+                if !matches!(attr.meta, syn::Meta::Path(_)) {
+                    attr.parse_nested_meta(|meta| {
+                        let ch: LitChar = meta.value()?.parse()?;
+                        if meta.path.is_ident("yes") { yes = ch.value() }
+                        else if meta.path.is_ident("no") { no = ch.value() }
+                        else if meta.path.is_ident("uncertain") {
+                            uncertain = ch.value()
+                        }
+                        else {
+                            return Err(meta.error(
+                                "expected `yes`, `no`, or `uncertain`"
+                            ))
+                        }
+                        Ok(())
+                    })?;
+                }
+                layout = Layout::Ternary { yes, no, uncertain };
+            }
+
+            else if attr.path().is_ident("timestamp") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("itch") { layout = Layout::ItchTime }
+                    else if meta.path.is_ident("ouch") { layout = Layout::OuchTime }
+                    else { return Err(meta.error("expected `itch` or `ouch`")) }
+                    Ok(())
+                })?;
+                if matches!(layout, Layout::Wire(_)) {
+                    return Err(syn::Error::new(
+                        attr.span(),
+                        "expected `#[timestamp(itch)]` or `#[timestamp(ouch)]`"
+                    ))
+                }
+            }
+        }
+
+        Ok(layout)
+    }
+
+    /// Number of bytes the field occupies, as a constant expression.
+    fn len(&self) -> TokenStream2 {
+        match self {
+            Layout::Wire(ty) => quote!{ <#ty as ::nsdq_util::Wire>::LEN },
+            Layout::Bool { .. } | Layout::Ternary { .. } => quote!{ 1usize },
+            Layout::ItchTime => quote!{ 6usize },
+            Layout::OuchTime => quote!{ 8usize },
+        }
+    }
+
+    /// Expression parsing the field from `input`, naming it in any error.
+    fn parse(&self, name: &str) -> TokenStream2 {
+
+        let len = self.len();
+        let parser = match self {
+
+            Layout::Wire(ty) => return quote!{
+                ::nsdq_util::error::field::<#ty>(#name, input)
+            },
+
+            Layout::Bool { yes, no } => quote!{
+                |input| ::nsdq_util::parse_bool_with_chars(#yes, #no, input)
+            },
+
+            Layout::Ternary { yes, no, uncertain } => quote!{
+                |input| ::nsdq_util::parse_ternary_with_chars(
+                    #yes, #no, #uncertain, input
+                )
+            },
+
+            Layout::ItchTime => quote!{ ::nsdq_util::Timestamp::parse_itch },
+            Layout::OuchTime => quote!{ ::nsdq_util::Timestamp::parse_ouch },
+        };
+
+        quote!{
+            ::nsdq_util::error::field_with(#name, Some(#len), #parser, input)
+        }
+    }
+
+    /// Statement writing `val` with `writer`.
+    fn encode(&self, val: TokenStream2) -> TokenStream2 {
+        match self {
+
+            Layout::Wire(_) => quote!{ writer.put(&#val)?; },
+
+            Layout::Bool { yes, no } => quote!{
+                writer.put_slice(
+                    &::nsdq_util::encode_bool_with_chars(#yes, #no, #val)
+                )?;
+            },
+
+            Layout::Ternary { yes, no, uncertain } => quote!{
+                writer.put_slice(
+                    &::nsdq_util::encode_ternary_with_chars(
+                        #yes, #no, #uncertain, #val
+                    )
+                )?;
+            },
+
+            Layout::ItchTime => quote!{
+                writer.put_slice(&#val.encode_itch())?;
+            },
+
+            Layout::OuchTime => quote!{
+                writer.put_slice(&#val.encode_ouch())?;
+            },
+        }
+    }
+}
+
+/// Read the optional message type byte from `#[tag(b'A')]`.
+fn message_tag(attrs: &[Attribute]) -> syn::Result<Option<LitByte>> {
+
+    attrs.iter()
+        .find(|attr| attr.path().is_ident("tag"))
+        .map(|attr| attr.parse_args::<LitByte>())
+        .transpose()
+}
+
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new(
+                input.span(),
+                "NsdqMessage requires a struct with named fields"
+            )),
+        },
+        _ => return Err(syn::Error::new(
+            input.span(),
+            "NsdqMessage can only be derived for structs"
+        )),
+    };
+
+    let tag = message_tag(&input.attrs)?;
+
+    let idents = fields.iter()
+        .map(|field| field.ident.clone().expect("Named fields"))
+        .collect::<Vec<_>>();
+    let layouts = fields.iter()
+        .map(Layout::from_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let lens = layouts.iter().map(Layout::len);
+    let parsers = idents.iter().zip(&layouts)
+        .map(|(ident, layout)| layout.parse(&ident.to_string()))
+        .collect::<Vec<_>>();
+    let encoders = idents.iter().zip(&layouts)
+        .map(|(ident, layout)| layout.encode(quote!{ self.#ident }));
+
+    let (tag_const, tag_len, tag_parser, tag_encode) = match &tag {
+
+        Some(tag) => (
+            quote!{
+                /// Byte identifying the message type.
+                pub const TAG: u8 = #tag;
+            },
+            quote!{ 1usize + },
+            quote!{
+                ::nsdq_util::error::field_with(
+                    "message_type",
+                    Some(1),
+                    |input| {
+                        use ::nsdq_util::__private::nom;
+
+                        let (rest, found) =
+                            nom::number::streaming::be_u8(input)?;
+                        if found != Self::TAG {
+                            return Err(nom::Err::Error(
+                                nom::error::Error::new(
+                                    input,
+                                    nom::error::ErrorKind::Tag
+                                )
+                            ))
+                        }
+                        Ok((rest, found))
+                    },
+                    input
+                )
+            },
+            quote!{ writer.put(&Self::TAG)?; },
+        ),
+
+        None => (quote!{}, quote!{}, quote!{ Ok((input, 0u8)) }, quote!{}),
+    };
+
+    Ok(quote!{
+
+        impl #impl_generics #name #ty_generics #where_clause {
+
+            #tag_const
+
+            /// Size of the encoded message in bytes.
+            pub const LEN: usize = #tag_len 0usize #(+ #lens)*;
+
+            /// Parse the message from its fields in declaration order.
+            /// Errors name the failed field, with the message as context.
+            pub fn parse(
+                input: &[u8]
+            ) -> ::nsdq_util::__private::nom::IResult<
+                &[u8],
+                Self,
+                ::nsdq_util::error::ParseError
+            > {
+
+                use ::nsdq_util::__private::nom::{ self, error::ContextError };
+                use ::nsdq_util::error::ParseError;
+
+                let start = input;
+                let context = |err: nom::Err<ParseError>| err.map(|e| {
+                    ParseError::add_context(start, #name_str, e)
+                });
+
+                let (input, _) = #tag_parser.map_err(context)?;
+                #(
+                    let (input, #idents) = #parsers.map_err(context)?;
+                )*
+
+                Ok((input, Self { #(#idents),* }))
+            }
+
+            /// Encode the message, including its type byte if tagged.
+            pub fn encode(&self) -> Vec<u8> {
+
+                let mut bytes = vec![0u8; Self::LEN];
+                <Self as ::nsdq_util::Wire>::encode_into(self, &mut bytes)
+                    .expect("Buffer is sized for the message");
+                bytes
+            }
+        }
+
+        impl #impl_generics ::nsdq_util::Wire for #name #ty_generics #where_clause {
+
+            const LEN: usize = <#name #ty_generics>::LEN;
+
+            fn parse(
+                input: &[u8]
+            ) -> ::nsdq_util::__private::nom::IResult<&[u8], Self> {
+                use ::nsdq_util::__private::nom;
+
+                <#name #ty_generics>::parse(input).map_err(|err| err.map(|e| {
+                    nom::error::Error::new(e.locate(input), e.kind)
+                }))
+            }
+
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, ::nsdq_util::error::TypeError> {
+
+                if buf.len() < Self::LEN {
+                    return Err(::nsdq_util::error::TypeError::BufferTooShort(
+                        Self::LEN,
+                        buf.len()
+                    ))
+                }
+
+                let mut writer = ::nsdq_util::Writer::new(buf);
+                #tag_encode
+                #( #encoders )*
+                Ok(writer.position())
+            }
+        }
+    })
+}
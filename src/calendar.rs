@@ -0,0 +1,263 @@
+
+use chrono::{ Datelike, Weekday };
+
+use crate::{ NaiveDate, NaiveTime };
+
+
+/// Nasdaq market session, for classifying the time of an event.
+/// The cross sessions are the windows in which the Net Order Imbalance
+/// Indicator is disseminated ahead of each cross, which executes at the
+/// end of the window.
+/// Continuous trading carries on through the closing cross window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Session {
+    /// Not a trading day, or outside of any session.
+    Closed,
+    /// 04:00 until the opening cross window.
+    PreMarket,
+    /// 09:28 until the opening cross at 09:30.
+    OpeningCross,
+    /// 09:30 until the closing cross window.
+    Regular,
+    /// 10 minutes before the closing cross at 16:00 (13:00 on half days).
+    ClosingCross,
+    /// After the close until 20:00 (17:00 on half days).
+    AfterHours,
+}
+
+fn hm(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).expect("Valid time of day")
+}
+
+/// Start of the pre-market session.
+pub fn pre_market_open() -> NaiveTime { hm(4, 0) }
+
+/// Start of the opening cross window.
+pub fn opening_cross_start() -> NaiveTime { hm(9, 28) }
+
+/// Opening cross, and start of the regular session.
+pub fn market_open() -> NaiveTime { hm(9, 30) }
+
+/// Closing cross, and end of the regular session,
+/// or `None` if the market is closed for the day.
+pub fn market_close(date: NaiveDate) -> Option<NaiveTime> {
+    match is_trading_day(date) {
+        true if is_half_day(date) => Some(hm(13, 0)),
+        true => Some(hm(16, 0)),
+        false => None,
+    }
+}
+
+/// End of the after-hours session,
+/// or `None` if the market is closed for the day.
+pub fn after_hours_close(date: NaiveDate) -> Option<NaiveTime> {
+    market_close(date).map(|close| close + chrono::Duration::hours(4))
+}
+
+
+/// Session in effect at a time of day on the given date.
+/// ```
+/// use nsdq_util::{ NaiveDate, NaiveTime };
+/// use nsdq_util::calendar::{ session_at, Session };
+///
+/// let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+/// let day = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+///
+/// assert_eq!(session_at(day, at(3, 59)), Session::Closed);
+/// assert_eq!(session_at(day, at(4, 0)), Session::PreMarket);
+/// assert_eq!(session_at(day, at(9, 28)), Session::OpeningCross);
+/// assert_eq!(session_at(day, at(9, 30)), Session::Regular);
+/// assert_eq!(session_at(day, at(15, 55)), Session::ClosingCross);
+/// assert_eq!(session_at(day, at(16, 0)), Session::AfterHours);
+/// assert_eq!(session_at(day, at(20, 0)), Session::Closed);
+///
+/// // Half day after Thanksgiving.
+/// let half = NaiveDate::from_ymd_opt(2025, 11, 28).unwrap();
+/// assert_eq!(session_at(half, at(12, 55)), Session::ClosingCross);
+/// assert_eq!(session_at(half, at(15, 0)), Session::AfterHours);
+/// assert_eq!(session_at(half, at(17, 0)), Session::Closed);
+///
+/// // Weekend and holiday.
+/// let saturday = NaiveDate::from_ymd_opt(2025, 6, 7).unwrap();
+/// assert_eq!(session_at(saturday, at(10, 0)), Session::Closed);
+/// let juneteenth = NaiveDate::from_ymd_opt(2025, 6, 19).unwrap();
+/// assert_eq!(session_at(juneteenth, at(10, 0)), Session::Closed);
+/// ```
+pub fn session_at(date: NaiveDate, time: NaiveTime) -> Session {
+
+    let (Some(close), Some(after_hours_close)) =
+        (market_close(date), after_hours_close(date))
+    else {
+        return Session::Closed
+    };
+    let closing_cross_start = close - chrono::Duration::minutes(10);
+
+    match time {
+        t if t < pre_market_open() => Session::Closed,
+        t if t < opening_cross_start() => Session::PreMarket,
+        t if t < market_open() => Session::OpeningCross,
+        t if t < closing_cross_start => Session::Regular,
+        t if t < close => Session::ClosingCross,
+        t if t < after_hours_close => Session::AfterHours,
+        _ => Session::Closed,
+    }
+}
+
+
+/// Whether the market is open on the date.
+pub fn is_trading_day(date: NaiveDate) -> bool {
+    !is_weekend(date) && !is_holiday(date)
+}
+
+/// Next trading day strictly after the date,
+/// or `None` if there is none before `NaiveDate::MAX`.
+/// ```
+/// use nsdq_util::NaiveDate;
+/// use nsdq_util::calendar::{ next_trading_day, previous_trading_day };
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+///
+/// // Good Friday, then the weekend.
+/// assert_eq!(next_trading_day(date(4, 17)), Some(date(4, 21)));
+/// assert_eq!(previous_trading_day(date(4, 21)), Some(date(4, 17)));
+/// assert_eq!(
+///     next_trading_day(date(12, 31)),
+///     NaiveDate::from_ymd_opt(2026, 1, 2)
+/// );
+///
+/// assert_eq!(next_trading_day(NaiveDate::MAX), None);
+/// assert_eq!(previous_trading_day(NaiveDate::MIN), None);
+/// ```
+pub fn next_trading_day(date: NaiveDate) -> Option<NaiveDate> {
+    date.iter_days()
+        .skip(1)
+        .find(|date| is_trading_day(*date))
+}
+
+/// Last trading day strictly before the date,
+/// or `None` if there is none after `NaiveDate::MIN`.
+pub fn previous_trading_day(date: NaiveDate) -> Option<NaiveDate> {
+    date.iter_days()
+        .rev()
+        .skip(1)
+        .find(|date| is_trading_day(*date))
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+
+/// Whether the date is a US equity market holiday,
+/// following the standard rules for when each holiday is observed.
+/// Unscheduled closures, such as days of national mourning, are not known.
+/// ```
+/// use nsdq_util::NaiveDate;
+/// use nsdq_util::calendar::is_holiday;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert!(is_holiday(date(2025, 1, 20)));  // Martin Luther King Jr. Day
+/// assert!(is_holiday(date(2025, 4, 18)));  // Good Friday
+/// assert!(is_holiday(date(2025, 11, 27))); // Thanksgiving
+/// assert!(is_holiday(date(2023, 1, 2)));   // New Year's Day, on a Sunday
+/// assert!(is_holiday(date(2021, 12, 24))); // Christmas, on a Saturday
+/// assert!(!is_holiday(date(2021, 12, 31))); // New Year's Day 2022 was a Saturday
+/// assert!(!is_holiday(date(2021, 6, 18)));  // Before Juneteenth was observed
+/// assert!(!is_holiday(date(2025, 10, 13))); // Columbus Day
+/// ```
+pub fn is_holiday(date: NaiveDate) -> bool {
+
+    let year = date.year();
+    let fixed = |month, day| observed(ymd(year, month, day));
+
+    // New Year's Day on a Saturday is not observed the year before.
+    let holidays = [
+        Some(fixed(1, 1)).filter(|d| d.year() == year),
+        Some(nth_weekday(year, 1, Weekday::Mon, 3)).filter(|_| year >= 1998),
+        Some(nth_weekday(year, 2, Weekday::Mon, 3)),
+        easter(year).pred_opt().and_then(|d| d.pred_opt()),
+        Some(last_weekday(year, 5, Weekday::Mon)),
+        Some(fixed(6, 19)).filter(|_| year >= 2022),
+        Some(fixed(7, 4)),
+        Some(nth_weekday(year, 9, Weekday::Mon, 1)),
+        Some(nth_weekday(year, 11, Weekday::Thu, 4)),
+        Some(fixed(12, 25)),
+    ];
+
+    holidays.contains(&Some(date))
+}
+
+/// Whether the market closes early, at 13:00, on the date:
+/// the day before Independence Day, the day after Thanksgiving,
+/// and Christmas Eve, when each is a trading day.
+/// ```
+/// use nsdq_util::NaiveDate;
+/// use nsdq_util::calendar::is_half_day;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// assert!(is_half_day(date(2025, 7, 3)));
+/// assert!(is_half_day(date(2025, 11, 28)));
+/// assert!(is_half_day(date(2025, 12, 24)));
+/// assert!(!is_half_day(date(2026, 7, 3))); // Independence Day observed
+/// assert!(!is_half_day(date(2022, 12, 23)));
+/// ```
+pub fn is_half_day(date: NaiveDate) -> bool {
+
+    let year = date.year();
+    let half_days = [
+        ymd(year, 7, 3),
+        nth_weekday(year, 11, Weekday::Thu, 4).succ_opt().expect("Valid date"),
+        ymd(year, 12, 24),
+    ];
+
+    half_days.contains(&date) && !is_weekend(date) && !is_holiday(date)
+}
+
+
+fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("Valid date")
+}
+
+/// Holidays on a Saturday are observed on Friday,
+/// and holidays on a Sunday are observed on Monday.
+fn observed(date: NaiveDate) -> NaiveDate {
+    match date.weekday() {
+        Weekday::Sat => date.pred_opt().expect("Valid date"),
+        Weekday::Sun => date.succ_opt().expect("Valid date"),
+        _ => date,
+    }
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u8) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+        .expect("Every month has at least four of each weekday")
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> NaiveDate {
+    NaiveDate::from_weekday_of_month_opt(year, month, weekday, 5)
+        .unwrap_or_else(|| nth_weekday(year, month, weekday, 4))
+}
+
+/// Easter Sunday, by the anonymous Gregorian algorithm.
+fn easter(year: i32) -> NaiveDate {
+
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    ymd(year, month as u32, day as u32)
+}
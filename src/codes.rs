@@ -0,0 +1,50 @@
+//! Protocol codes for ITCH and OUCH in one place.
+//! Each code documents its meaning as given in the specification,
+//! which is also available at runtime from `description()`.
+//!
+//! Codes whose name is shared by both protocols with different values
+//! (`Side`, `CrossType` and `EventCode`) are only available
+//! from the [`itch`] and [`ouch`] modules.
+//! ```
+//! use nsdq_util::codes::{ self, TradingState, RejectReason };
+//!
+//! let (_, state) = TradingState::parse(b"H").unwrap();
+//! assert_eq!(state.description(), "Halted across all U.S. equity markets / SROs");
+//!
+//! assert_eq!(RejectReason::FatFinger.description(), "Fat finger");
+//! assert_eq!(codes::ouch::Side::SellShort.encode(), *b"T");
+//! assert_eq!(codes::itch::CrossType::Opening.description(), "Nasdaq Opening Cross");
+//! ```
+
+pub use crate::itch::codes as itch;
+pub use crate::ouch::codes as ouch;
+
+pub use itch::{
+    MarketCategory,
+    FinancialStatus,
+    IssueClassification,
+    IssueSubType,
+    Authenticity,
+    LuldTier,
+    TradingState,
+    RegShoAction,
+    MarketMakerMode,
+    MarketParticipantState,
+    BreachedLevel,
+    IpoReleaseQualifier,
+    MarketCode,
+    HaltAction,
+    ImbalanceDirection,
+    PriceVariation,
+    InterestFlag,
+};
+pub use ouch::{
+    TimeInForce,
+    Display,
+    Capacity,
+    OrderState,
+    CancelReason,
+    BrokenTradeReason,
+    RestateReason,
+    RejectReason,
+};
@@ -1,26 +1,252 @@
-
 /// Invalid type construction.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TypeError {
     InvalidPrice(String),
     InvalidString(String, String),
     InvalidTime(u64),
+    /// Bytes needed and bytes available for encoding.
+    BufferTooShort(usize, usize),
+    /// Length and maximum length of a length-prefixed field.
+    TooLong(usize, usize),
 }
 
 use std::fmt;
 
 impl fmt::Display for TypeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        
+
         let msg = match self {
             TypeError::InvalidPrice(val) => format!("Price: {}", val),
             TypeError::InvalidString(k, val) => format!("{}: {}", k, val),
             TypeError::InvalidTime(val) => format!("Time: {} (nanosec)", val),
+            TypeError::BufferTooShort(needed, available) => format!(
+                "Buffer: {} bytes needed, {} available", needed, available
+            ),
+            TypeError::TooLong(len, max) => format!(
+                "Length: {} bytes, at most {} allowed", len, max
+            ),
         };
-        
+
         write!(f, "Invalid Type - {}", msg)
     }
 }
 
 impl std::error::Error for TypeError {}
 
+
+use nom::error::{ ErrorKind, ContextError, FromExternalError };
+
+use crate::Wire;
+
+/// Failure to parse a message, locating the field that failed.
+/// Message parsers name each field as they decode it,
+/// and the dispatching enums add the message name as context.
+/// ```
+/// use nsdq_util::itch::ItchMessage;
+///
+/// // Tag, header, then an order reference number.
+/// let mut bytes = vec![b'A'];
+/// bytes.extend([0u8; 10]);
+/// bytes.extend(42u64.to_be_bytes());
+/// bytes.push(b'Z');
+/// bytes.extend([0u8; 16]);
+///
+/// let err = match ItchMessage::parse(&bytes) {
+///     Err(nom::Err::Error(err)) => err,
+///     _ => panic!("Side 'Z' is invalid"),
+/// };
+///
+/// assert_eq!(err.message(), Some("AddOrder"));
+/// assert_eq!(err.field, Some("side"));
+/// assert_eq!(err.offset(), Some(19));
+/// assert_eq!(err.expected_len, Some(1));
+/// assert_eq!(&err.bytes[..], b"Z");
+/// assert!(err.type_name.unwrap().ends_with("Side"));
+/// assert_eq!(
+///     err.to_string(),
+///     "Parse Error - AddOrder.side (nsdq_util::itch::codes::Side) \
+///     at byte 19: found [5A], expected 1 bytes (Tag)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Parser that failed.
+    pub kind: ErrorKind,
+    /// Name of the field being decoded, if known.
+    pub field: Option<&'static str>,
+    /// Type of the field being decoded, if known.
+    pub type_name: Option<&'static str>,
+    /// Number of bytes the field occupies, if fixed.
+    pub expected_len: Option<usize>,
+    /// Bytes of the field that failed to decode.
+    pub bytes: Box<[u8]>,
+    /// Length of the input remaining from the start of the failed field.
+    pub remaining: usize,
+    /// Enclosing contexts, innermost first, each with the byte offset
+    /// of the failed field from the start of that context.
+    pub context: Vec<(&'static str, usize)>,
+    /// Type error behind the failure, if any.
+    pub source: Option<Box<TypeError>>,
+}
+
+impl ParseError {
+
+    fn new(input: &[u8], kind: ErrorKind) -> Self {
+        ParseError {
+            kind,
+            field: None,
+            type_name: None,
+            expected_len: None,
+            bytes: Box::default(),
+            remaining: input.len(),
+            context: Vec::new(),
+            source: None,
+        }
+    }
+
+    /// Error for a message type byte that is not recognized.
+    pub(crate) fn unknown_message(input: &[u8]) -> Self {
+        let mut err = ParseError::new(input, ErrorKind::Tag);
+        err.field = Some("message_type");
+        err.expected_len = Some(1);
+        err.bytes = input[..input.len().min(1)].into();
+        err
+    }
+
+    /// Innermost context, usually the message type.
+    pub fn message(&self) -> Option<&'static str> {
+        self.context.first().map(|(name, _)| *name)
+    }
+
+    /// Offset of the failed field from the start of the innermost context.
+    pub fn offset(&self) -> Option<usize> {
+        self.context.first().map(|(_, offset)| *offset)
+    }
+
+    /// The part of the original input where the failed field starts.
+    pub fn locate<'a>(&self, input: &'a [u8]) -> &'a [u8] {
+        &input[input.len().saturating_sub(self.remaining)..]
+    }
+}
+
+impl nom::error::ParseError<&[u8]> for ParseError {
+
+    fn from_error_kind(input: &[u8], kind: ErrorKind) -> Self {
+        ParseError::new(input, kind)
+    }
+
+    /// Keeps the innermost error, which locates the failure.
+    fn append(_input: &[u8], _kind: ErrorKind, other: Self) -> Self { other }
+}
+
+impl ContextError<&[u8]> for ParseError {
+
+    fn add_context(input: &[u8], ctx: &'static str, mut other: Self) -> Self {
+        let offset = input.len().saturating_sub(other.remaining);
+        other.context.push((ctx, offset));
+        other
+    }
+}
+
+impl FromExternalError<&[u8], TypeError> for ParseError {
+
+    fn from_external_error(input: &[u8], kind: ErrorKind, e: TypeError) -> Self {
+        let mut err = ParseError::new(input, kind);
+        err.source = Some(Box::new(e));
+        err
+    }
+}
+
+impl From<TypeError> for ParseError {
+    fn from(e: TypeError) -> Self {
+        let mut err = ParseError::new(&[], ErrorKind::Verify);
+        err.source = Some(Box::new(e));
+        err
+    }
+}
+
+impl From<nom::error::Error<&[u8]>> for ParseError {
+    fn from(e: nom::error::Error<&[u8]>) -> Self {
+        ParseError::new(e.input, e.code)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        write!(f, "Parse Error - ")?;
+        match (self.message(), self.field) {
+            (Some(message), Some(field)) => write!(f, "{}.{}", message, field)?,
+            (Some(name), None) | (None, Some(name)) => write!(f, "{}", name)?,
+            (None, None) => write!(f, "input")?,
+        }
+        if let Some(type_name) = self.type_name {
+            write!(f, " ({})", type_name)?;
+        }
+        if let Some(offset) = self.offset() {
+            write!(f, " at byte {}", offset)?;
+        }
+
+        let found = self.bytes.iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(" ");
+        write!(f, ": found [{}]", found)?;
+        if let Some(len) = self.expected_len {
+            write!(f, ", expected {} bytes", len)?;
+        }
+        match &self.source {
+            Some(source) => write!(f, " ({})", source),
+            None => write!(f, " ({:?})", self.kind),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|e| e as _)
+    }
+}
+
+
+/// Parse a fixed-width field, naming it in any error.
+/// ```
+/// use nsdq_util::error::field;
+///
+/// let (rest, qty): (_, u32) = field("quantity", &[0, 0, 0, 100, 1]).unwrap();
+/// assert_eq!(qty, 100);
+/// assert_eq!(rest, &[1]);
+///
+/// let err = match field::<bool>("printable", b"X") {
+///     Err(nom::Err::Error(err)) => err,
+///     _ => panic!("'X' is not a boolean"),
+/// };
+/// assert_eq!(err.field, Some("printable"));
+/// assert_eq!(&err.bytes[..], b"X");
+/// ```
+pub fn field<'a, T: Wire>(
+    name: &'static str,
+    input: &'a [u8]
+) -> nom::IResult<&'a [u8], T, ParseError> {
+    field_with(name, Some(T::LEN), T::parse, input)
+}
+
+/// Parse a field with any parser, naming it in any error.
+/// `len` is the width of the field, if it is fixed.
+pub fn field_with<'a, T>(
+    name: &'static str,
+    len: Option<usize>,
+    parser: impl FnOnce(&'a [u8]) -> nom::IResult<&'a [u8], T>,
+    input: &'a [u8]
+) -> nom::IResult<&'a [u8], T, ParseError> {
+
+    parser(input).map_err(|err| err.map(|e| {
+        let mut err = ParseError::new(input, e.code);
+        err.field = Some(name);
+        err.type_name = Some(std::any::type_name::<T>());
+        err.expected_len = len;
+        err.bytes = input[..len.unwrap_or(0).min(input.len())].into();
+        err
+    }))
+}
@@ -0,0 +1,250 @@
+
+pub mod codes;
+pub mod system;
+pub mod stock;
+pub mod mwcb;
+pub mod order;
+pub mod trade;
+pub mod imbalance;
+pub mod view;
+
+pub use codes::*;
+pub use system::SystemEvent;
+pub use stock::{
+    StockDirectory,
+    TradingAction,
+    RegShoRestriction,
+    MarketParticipantPosition,
+    IpoQuotingPeriod,
+    LuldAuctionCollar,
+    OperationalHalt,
+};
+pub use mwcb::{ MwcbDeclineLevel, MwcbStatus };
+pub use order::{
+    AddOrder,
+    AddOrderWithMpid,
+    OrderExecuted,
+    OrderExecutedWithPrice,
+    OrderCancel,
+    OrderDelete,
+    OrderReplace,
+};
+pub use trade::{ Trade, CrossTrade, BrokenTrade };
+pub use imbalance::{ NetOrderImbalance, RetailPriceImprovement };
+pub use view::*;
+
+use nom::{
+    number::streaming::{ be_u8, be_u16 },
+    error::ContextError,
+};
+
+use crate::{
+    Wire,
+    error::{ TypeError, ParseError, field, field_with },
+    types::wire::write_bytes,
+    Timestamp,
+};
+
+
+/// Fields common to every ITCH message, following the message type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Header {
+    /// Locate code identifying the security (0 for market-wide messages).
+    pub stock_locate: u16,
+    /// Nasdaq internal tracking number.
+    pub tracking_number: u16,
+    /// Time of the event, as nanoseconds since midnight.
+    pub timestamp: Timestamp,
+}
+
+impl Header {
+
+    /// Size of the header in bytes.
+    pub const LEN: usize = 10;
+
+    /// Parse the header from 10 bytes.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, stock_locate) = be_u16(input)?;
+        let (input, tracking_number) = be_u16(input)?;
+        let (input, timestamp) = Timestamp::parse_itch(input)?;
+
+        Ok((input, Self { stock_locate, tracking_number, timestamp }))
+    }
+
+    /// Encode the header as 10 bytes.
+    pub fn encode(&self) -> [u8; 10] {
+
+        let mut bytes = [0u8; 10];
+        bytes[..2].copy_from_slice(&self.stock_locate.to_be_bytes());
+        bytes[2..4].copy_from_slice(&self.tracking_number.to_be_bytes());
+        bytes[4..].copy_from_slice(&self.timestamp.encode_itch());
+        bytes
+    }
+}
+
+impl Wire for Header {
+    const LEN: usize = Header::LEN;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Header::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
+}
+
+
+/// Generates the dispatching enum over all message types.
+macro_rules! itch_messages {
+    ($($kind:ident),*$(,)?) => {
+
+        /// Any ITCH 5.0 message, dispatched on the message type byte.
+        /// ```
+        /// use nsdq_util::{ NaiveTime, Price, StockSymbol };
+        /// use nsdq_util::itch::{ ItchMessage, AddOrder, Header, Side };
+        ///
+        /// let add = AddOrder {
+        ///     header: Header {
+        ///         stock_locate: 1,
+        ///         tracking_number: 0,
+        ///         timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+        ///     },
+        ///     order_reference_number: 42,
+        ///     side: Side::Buy,
+        ///     shares: 100,
+        ///     stock: StockSymbol::from("AAPL").unwrap(),
+        ///     price: Price::new(150_0000).unwrap(),
+        /// };
+        ///
+        /// let bytes = add.encode();
+        /// assert_eq!(bytes.len(), AddOrder::LEN);
+        /// assert_eq!(bytes[0], b'A');
+        ///
+        /// let (rest, msg) = ItchMessage::parse(&bytes).unwrap();
+        /// assert!(rest.is_empty());
+        /// assert_eq!(msg.message_type(), b'A');
+        /// assert_eq!(msg, ItchMessage::AddOrder(add));
+        /// assert_eq!(msg.encode(), bytes);
+        ///
+        /// assert!(ItchMessage::parse(b"?").is_err());
+        /// ```
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum ItchMessage {$(
+            $kind($kind),
+        )*}
+
+        impl ItchMessage {
+
+            /// Byte identifying the message type.
+            pub fn message_type(&self) -> u8 {
+                match self {$(
+                    ItchMessage::$kind(_) => $kind::TAG,
+                )*}
+            }
+
+            /// Fields common to all message types.
+            pub fn header(&self) -> &Header {
+                match self {$(
+                    ItchMessage::$kind(msg) => &msg.header,
+                )*}
+            }
+
+            /// Parse any message, starting with its type byte.
+            /// Errors name the message type as their context.
+            pub fn parse(
+                input: &[u8]
+            ) -> nom::IResult<&[u8], Self, ParseError> {
+
+                let (_, tag) = field::<u8>("message_type", input)?;
+                match tag {
+                    $(
+                        $kind::TAG => match $kind::parse(input) {
+                            Ok((rest, msg)) => Ok((rest, ItchMessage::$kind(msg))),
+                            Err(err) => Err(err.map(|e| {
+                                ParseError::add_context(input, stringify!($kind), e)
+                            })),
+                        },
+                    )*
+                    _ => Err(nom::Err::Error(ParseError::unknown_message(input))),
+                }
+            }
+
+            /// Encode the message, including its type byte.
+            pub fn encode(&self) -> Vec<u8> {
+                match self {$(
+                    ItchMessage::$kind(msg) => msg.encode(),
+                )*}
+            }
+        }
+
+        $(
+            impl From<$kind> for ItchMessage {
+                fn from(msg: $kind) -> Self { ItchMessage::$kind(msg) }
+            }
+        )*
+    }
+}
+
+itch_messages!{
+    SystemEvent,
+    StockDirectory,
+    TradingAction,
+    RegShoRestriction,
+    MarketParticipantPosition,
+    MwcbDeclineLevel,
+    MwcbStatus,
+    IpoQuotingPeriod,
+    LuldAuctionCollar,
+    OperationalHalt,
+    AddOrder,
+    AddOrderWithMpid,
+    OrderExecuted,
+    OrderExecutedWithPrice,
+    OrderCancel,
+    OrderDelete,
+    OrderReplace,
+    Trade,
+    CrossTrade,
+    BrokenTrade,
+    NetOrderImbalance,
+    RetailPriceImprovement,
+}
+
+
+/// Parse the message type byte and the common header,
+/// failing if the type does not match.
+pub(crate) fn parse_tagged(
+    tag: u8,
+    input: &[u8]
+) -> nom::IResult<&[u8], Header, ParseError> {
+
+    let (input, _) = field_with(
+        "message_type",
+        Some(1),
+        |input| message_type(tag, input),
+        input
+    )?;
+
+    field("header", input)
+}
+
+/// Parse the message type byte, failing if it does not match.
+fn message_type(tag: u8, input: &[u8]) -> nom::IResult<&[u8], u8> {
+
+    let (rest, found) = be_u8(input)?;
+    if found != tag {
+        return Err(nom::Err::Error(
+            nom::error::Error::new(input, nom::error::ErrorKind::Tag)
+        ))
+    }
+
+    Ok((rest, found))
+}
+
+/// Start encoding a message with its type byte and the common header.
+pub(crate) fn encode_tagged(tag: u8, header: &Header, len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    bytes.push(tag);
+    bytes.extend(header.encode());
+    bytes
+}
@@ -0,0 +1,309 @@
+
+use crate::define_enum;
+
+
+define_enum!{
+    EventCode:
+        "System event codes, signaling market- or data feed-wide events.";
+
+    ['O'] StartOfMessages
+        "Outside of time stamp messages, the start of day message \
+        is the first message sent in any trading day.",
+    ['S'] StartOfSystemHours
+        "Nasdaq is open and ready to start accepting orders.",
+    ['Q'] StartOfMarketHours
+        "Market hours orders are available for execution.",
+    ['M'] EndOfMarketHours
+        "Market hours orders are no longer available for execution.",
+    ['E'] EndOfSystemHours
+        "Nasdaq is now closed and will not accept any new orders today.",
+    ['C'] EndOfMessages
+        "This is always the last message sent in any trading day.",
+}
+
+define_enum!{
+    MarketCategory:
+        "Listing market or listing market tier for the issue.";
+
+    ['Q'] NasdaqGlobalSelect "Nasdaq Global Select Market",
+    ['G'] NasdaqGlobal "Nasdaq Global Market",
+    ['S'] NasdaqCapital "Nasdaq Capital Market",
+    ['N'] Nyse "New York Stock Exchange (NYSE)",
+    ['A'] NyseAmerican "NYSE American",
+    ['P'] NyseArca "NYSE Arca",
+    ['Z'] Bats "BATS Z Exchange",
+    ['V'] Iex "Investors' Exchange, LLC",
+    [' '] NotAvailable "Not available",
+}
+
+define_enum!{
+    FinancialStatus:
+        "Financial Status Indicator, for Nasdaq-listed issues.";
+
+    ['D'] Deficient "Deficient",
+    ['E'] Delinquent "Delinquent",
+    ['Q'] Bankrupt "Bankrupt",
+    ['S'] Suspended "Suspended",
+    ['G'] DeficientBankrupt "Deficient and Bankrupt",
+    ['H'] DeficientDelinquent "Deficient and Delinquent",
+    ['J'] DelinquentBankrupt "Delinquent and Bankrupt",
+    ['K'] DeficientDelinquentBankrupt "Deficient, Delinquent and Bankrupt",
+    ['C'] CreationsSuspended
+        "Creations and/or Redemptions Suspended for Exchange Traded Product",
+    ['N'] Normal
+        "Normal (Default): Issuer is NOT Deficient, Delinquent, or Bankrupt",
+    [' '] NotAvailable
+        "Not available. Firms should refer to SIAC feeds for code if needed.",
+}
+
+define_enum!{
+    IssueClassification:
+        "Security class for the issue as assigned by Nasdaq.";
+
+    ['A'] AmericanDepositaryShare "American Depositary Share",
+    ['B'] Bond "Bond",
+    ['C'] CommonStock "Common Stock",
+    ['F'] DepositoryReceipt "Depository Receipt",
+    ['I'] Rule144A "144A",
+    ['L'] LimitedPartnership "Limited Partnership",
+    ['N'] Notes "Notes",
+    ['O'] OrdinaryShare "Ordinary Share",
+    ['P'] PreferredStock "Preferred Stock",
+    ['Q'] OtherSecurities "Other Securities",
+    ['R'] Right "Right",
+    ['S'] SharesOfBeneficialInterest "Shares of Beneficial Interest",
+    ['T'] ConvertibleDebenture "Convertible Debenture",
+    ['U'] Unit "Unit",
+    ['V'] UnitsBenefitInterest "Units/Benif Int",
+    ['W'] Warrant "Warrant",
+}
+
+define_enum!{
+    IssueSubType [2usize]
+        "Issue sub-type, padded on the right with a space where \
+        the code is a single character.";
+
+    [b"A "] PreferredTrustSecurities "Preferred Trust Securities",
+    [b"AI"] AlphaIndexEtns "Alpha Index ETNs",
+    [b"B "] IndexBasedDerivative "Index Based Derivative",
+    [b"C "] CommonShares "Common Shares",
+    [b"CB"] CommodityBasedTrustShares "Commodity Based Trust Shares",
+    [b"CF"] CommodityFuturesTrustShares "Commodity Futures Trust Shares",
+    [b"CL"] CommodityLinkedSecurities "Commodity-Linked Securities",
+    [b"CM"] CommodityIndexTrustShares "Commodity Index Trust Shares",
+    [b"CO"] CollateralizedMortgageObligation
+        "Collateralized Mortgage Obligation",
+    [b"CT"] CurrencyTrustShares "Currency Trust Shares",
+    [b"CU"] CommodityCurrencyLinkedSecurities
+        "Commodity-Currency-Linked Securities",
+    [b"CW"] CurrencyWarrants "Currency Warrants",
+    [b"D "] GlobalDepositaryShares "Global Depositary Shares",
+    [b"E "] EtfPortfolioDepositaryReceipt "ETF-Portfolio Depositary Receipt",
+    [b"EG"] EquityGoldShares "Equity Gold Shares",
+    [b"EI"] EtnEquityIndexLinked "ETN-Equity Index-Linked Securities",
+    [b"EM"] NextShares "NextShares Exchange Traded Managed Fund",
+    [b"EN"] ExchangeTradedNotes "Exchange Traded Notes",
+    [b"EU"] EquityUnits "Equity Units",
+    [b"F "] Holdrs "HOLDRS",
+    [b"FI"] EtnFixedIncomeLinked "ETN-Fixed Income-Linked Securities",
+    [b"FL"] EtnFuturesLinked "ETN-Futures-Linked Securities",
+    [b"G "] GlobalShares "Global Shares",
+    [b"I "] EtfIndexFundShares "ETF-Index Fund Shares",
+    [b"IR"] InterestRate "Interest Rate",
+    [b"IW"] IndexWarrant "Index Warrant",
+    [b"IX"] IndexLinkedExchangeableNotes "Index-Linked Exchangeable Notes",
+    [b"J "] CorporateBackedTrustSecurity "Corporate Backed Trust Security",
+    [b"L "] ContingentLitigationRight "Contingent Litigation Right",
+    [b"LL"] Llc "Limited Liability Company (LLC)",
+    [b"M "] EquityBasedDerivative "Equity-Based Derivative",
+    [b"MF"] ManagedFundShares "Managed Fund Shares",
+    [b"ML"] EtnMultiFactorIndexLinked
+        "ETN-Multi-Factor Index-Linked Securities",
+    [b"MT"] ManagedTrustSecurities "Managed Trust Securities",
+    [b"N "] NyRegistryShares "NY Registry Shares",
+    [b"O "] OpenEndedMutualFund "Open Ended Mutual Fund",
+    [b"P "] PrivatelyHeldSecurity "Privately Held Security",
+    [b"PP"] PoisonPill "Poison Pill",
+    [b"PU"] PartnershipUnits "Partnership Units",
+    [b"Q "] ClosedEndFunds "Closed-End Funds",
+    [b"R "] RegS "Reg-S",
+    [b"RC"] CommodityRedeemableCommodityLinked
+        "Commodity-Redeemable Commodity-Linked Securities",
+    [b"RF"] EtnRedeemableFuturesLinked
+        "ETN-Redeemable Futures-Linked Securities",
+    [b"RT"] Reit "REIT",
+    [b"RU"] CommodityRedeemableCurrencyLinked
+        "Commodity-Redeemable Currency-Linked Securities",
+    [b"S "] Seed "SEED",
+    [b"SC"] SpotRateClosing "Spot Rate Closing",
+    [b"SI"] SpotRateIntraday "Spot Rate Intraday",
+    [b"T "] TrackingStock "Tracking Stock",
+    [b"TC"] TrustCertificates "Trust Certificates",
+    [b"TU"] TrustUnits "Trust Units",
+    [b"U "] Portal "Portal",
+    [b"V "] ContingentValueRight "Contingent Value Right",
+    [b"W "] TrustIssuedReceipts "Trust Issued Receipts",
+    [b"WC"] WorldCurrencyOption "World Currency Option",
+    [b"X "] Trust "Trust",
+    [b"Y "] Other "Other",
+    [b"Z "] NotApplicable "Not Applicable",
+}
+
+define_enum!{
+    Authenticity:
+        "Denotes if an issue or quoting participant record is set-up \
+        in Nasdaq systems in a live/production, test, or demo state.";
+
+    ['P'] Production "Live/Production",
+    ['T'] Test "Test",
+}
+
+define_enum!{
+    LuldTier:
+        "Indicates which Limit Up / Limit Down price band calculation \
+        parameter is to be used for the instrument.";
+
+    ['1'] Tier1 "Tier 1 NMS Stocks and select ETPs",
+    ['2'] Tier2 "Tier 2 NMS Stocks",
+    [' '] NotApplicable "Not applicable",
+}
+
+define_enum!{
+    TradingState:
+        "Current trading state for the stock.";
+
+    ['H'] Halted "Halted across all U.S. equity markets / SROs",
+    ['P'] Paused "Paused across all U.S. equity markets / SROs",
+    ['Q'] QuotationOnly "Quotation only period for cross-SRO halt or pause",
+    ['T'] Trading "Trading on Nasdaq",
+}
+
+define_enum!{
+    RegShoAction:
+        "Reg SHO Short Sale Price Test Restriction status.";
+
+    ['0'] NoPriceTest "No price test in place",
+    ['1'] RestrictionInEffect
+        "Reg SHO Short Sale Price Test Restriction in effect \
+        due to an intra-day price drop in security",
+    ['2'] RestrictionRemains
+        "Reg SHO Short Sale Price Test Restriction remains in effect",
+}
+
+define_enum!{
+    MarketMakerMode:
+        "Quoting participant's registration status in relation to SEC \
+        Rules 101 and 104 of Regulation M.";
+
+    ['N'] Normal "Normal",
+    ['P'] Passive "Passive",
+    ['S'] Syndicate "Syndicate",
+    ['R'] PreSyndicate "Pre-syndicate",
+    ['L'] Penalty "Penalty",
+}
+
+define_enum!{
+    MarketParticipantState:
+        "Market participant's current registration status in the issue.";
+
+    ['A'] Active "Active",
+    ['E'] Excused "Excused/Withdrawn",
+    ['W'] Withdrawn "Withdrawn",
+    ['S'] Suspended "Suspended",
+    ['D'] Deleted "Deleted",
+}
+
+define_enum!{
+    BreachedLevel:
+        "Market-Wide Circuit Breaker level that has been breached.";
+
+    ['1'] Level1 "Level 1",
+    ['2'] Level2 "Level 2",
+    ['3'] Level3 "Level 3",
+}
+
+define_enum!{
+    IpoReleaseQualifier:
+        "Qualifier for the IPO quotation release time.";
+
+    ['A'] Anticipated "Anticipated Quotation Release Time",
+    ['C'] Canceled "IPO Release Canceled/Postponed",
+}
+
+define_enum!{
+    MarketCode:
+        "Nasdaq market for which an operational halt was generated.";
+
+    ['Q'] Nasdaq "Nasdaq",
+    ['B'] Bx "BX",
+    ['X'] Psx "PSX",
+}
+
+define_enum!{
+    HaltAction:
+        "Operational halt status for the security.";
+
+    ['H'] Halted "Operationally Halted on the identified Market",
+    ['T'] Resumed "Operational Halt has been lifted and Trading resumed",
+}
+
+define_enum!{
+    Side:
+        "The type of order being added.";
+
+    ['B'] Buy "Buy Order",
+    ['S'] Sell "Sell Order",
+}
+
+define_enum!{
+    CrossType:
+        "The Nasdaq cross session.";
+
+    ['O'] Opening "Nasdaq Opening Cross",
+    ['C'] Closing "Nasdaq Closing Cross",
+    ['H'] Halted "Cross for IPO and halted / paused securities",
+    ['I'] Intraday "Nasdaq Cross Network: Intraday Cross and Post-Close Cross",
+    ['A'] ExtendedTradingClose "Extended Trading Close",
+}
+
+define_enum!{
+    ImbalanceDirection:
+        "The market side of the order imbalance.";
+
+    ['B'] Buy "Buy imbalance",
+    ['S'] Sell "Sell imbalance",
+    ['N'] NoImbalance "No imbalance",
+    ['O'] Insufficient "Insufficient orders to calculate",
+    ['P'] Paused "Paused",
+}
+
+define_enum!{
+    PriceVariation:
+        "Absolute value of the percentage of deviation of the Near \
+        Indicative Clearing Price to the nearest Current Reference Price.";
+
+    ['L'] Under1 "Less than 1%",
+    ['1'] From1To2 "1 to 1.99%",
+    ['2'] From2To3 "2 to 2.99%",
+    ['3'] From3To4 "3 to 3.99%",
+    ['4'] From4To5 "4 to 4.99%",
+    ['5'] From5To6 "5 to 5.99%",
+    ['6'] From6To7 "6 to 6.99%",
+    ['7'] From7To8 "7 to 7.99%",
+    ['8'] From8To9 "8 to 8.99%",
+    ['9'] From9To10 "9 to 9.99%",
+    ['A'] From10To20 "10 to 19.99%",
+    ['B'] From20To30 "20 to 29.99%",
+    ['C'] Over30 "30% or greater",
+    [' '] NotCalculated "Cannot be calculated",
+}
+
+define_enum!{
+    InterestFlag:
+        "Retail Price Improvement interest on the book.";
+
+    ['B'] Buy "RPI orders available on the buy side",
+    ['S'] Sell "RPI orders available on the sell side",
+    ['A'] Both "RPI orders available on both sides (buy and sell)",
+    ['N'] NoInterest "No RPI orders available",
+}
@@ -0,0 +1,148 @@
+
+use crate::{
+    error::{ ParseError, field },
+    StockSymbol,
+    Price,
+};
+
+use super::{
+    Header,
+    CrossType,
+    ImbalanceDirection,
+    PriceVariation,
+    InterestFlag,
+    parse_tagged,
+    encode_tagged,
+};
+
+
+/// Net Order Imbalance Indicator (NOII), disseminated ahead of a cross.
+/// ```
+/// use nsdq_util::{ NaiveTime, Price, StockSymbol };
+/// use nsdq_util::itch::*;
+///
+/// let msg = NetOrderImbalance {
+///     header: Header {
+///         stock_locate: 1,
+///         tracking_number: 0,
+///         timestamp: NaiveTime::from_hms_opt(15, 50, 0).unwrap().into(),
+///     },
+///     paired_shares: 50_000,
+///     imbalance_shares: 1_200,
+///     imbalance_direction: ImbalanceDirection::Buy,
+///     stock: StockSymbol::from("AAPL").unwrap(),
+///     far_price: Price::new(150_1000).unwrap(),
+///     near_price: Price::new(150_0500).unwrap(),
+///     current_reference_price: Price::new(150_0000).unwrap(),
+///     cross_type: CrossType::Closing,
+///     price_variation: PriceVariation::Under1,
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), NetOrderImbalance::LEN);
+///
+/// let (_, parsed) = ItchMessage::parse(&bytes).unwrap();
+/// assert_eq!(parsed, ItchMessage::NetOrderImbalance(msg));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetOrderImbalance {
+    pub header: Header,
+    /// Total number of shares eligible to be matched
+    /// at the Current Reference Price.
+    pub paired_shares: u64,
+    /// Number of shares not paired at the Current Reference Price.
+    pub imbalance_shares: u64,
+    pub imbalance_direction: ImbalanceDirection,
+    pub stock: StockSymbol,
+    /// Hypothetical auction-clearing price for cross orders only.
+    pub far_price: Price<u32, 4>,
+    /// Hypothetical auction-clearing price for cross orders
+    /// as well as continuous orders.
+    pub near_price: Price<u32, 4>,
+    /// Price at which the NOII shares are being calculated.
+    pub current_reference_price: Price<u32, 4>,
+    pub cross_type: CrossType,
+    pub price_variation: PriceVariation,
+}
+
+impl NetOrderImbalance {
+
+    pub const TAG: u8 = b'I';
+    pub const LEN: usize = 50;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, paired_shares) = field("paired_shares", input)?;
+        let (input, imbalance_shares) = field("imbalance_shares", input)?;
+        let (input, imbalance_direction) = field("imbalance_direction", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, far_price) = field("far_price", input)?;
+        let (input, near_price) = field("near_price", input)?;
+        let (input, current_reference_price) =
+            field("current_reference_price", input)?;
+        let (input, cross_type) = field("cross_type", input)?;
+        let (input, price_variation) = field("price_variation", input)?;
+
+        Ok((input, Self {
+            header,
+            paired_shares,
+            imbalance_shares,
+            imbalance_direction,
+            stock,
+            far_price,
+            near_price,
+            current_reference_price,
+            cross_type,
+            price_variation,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.paired_shares.to_be_bytes());
+        bytes.extend(self.imbalance_shares.to_be_bytes());
+        bytes.extend(self.imbalance_direction.encode());
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.far_price.encode());
+        bytes.extend(self.near_price.encode());
+        bytes.extend(self.current_reference_price.encode());
+        bytes.extend(self.cross_type.encode());
+        bytes.extend(self.price_variation.encode());
+        bytes
+    }
+}
+
+
+/// Retail Price Improvement Indicator (RPII), showing the presence of
+/// RPI orders on the book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RetailPriceImprovement {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub interest_flag: InterestFlag,
+}
+
+impl RetailPriceImprovement {
+
+    pub const TAG: u8 = b'N';
+    pub const LEN: usize = 20;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, interest_flag) = field("interest_flag", input)?;
+
+        Ok((input, Self { header, stock, interest_flag }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.interest_flag.encode());
+        bytes
+    }
+}
@@ -0,0 +1,97 @@
+
+use crate::{
+    error::{ ParseError, field },
+    MwcbLevel,
+};
+
+use super::{ Header, BreachedLevel, parse_tagged, encode_tagged };
+
+
+/// Market-Wide Circuit Breaker (MWCB) breach points for the trading day.
+/// ```
+/// use nsdq_util::{ MwcbLevel, NaiveTime };
+/// use nsdq_util::itch::{ MwcbDeclineLevel, Header };
+///
+/// let msg = MwcbDeclineLevel {
+///     header: Header {
+///         stock_locate: 0,
+///         tracking_number: 0,
+///         timestamp: NaiveTime::from_hms_opt(9, 0, 0).unwrap().into(),
+///     },
+///     level_1: MwcbLevel::new(3_500_00000000).unwrap(),
+///     level_2: MwcbLevel::new(3_300_00000000).unwrap(),
+///     level_3: MwcbLevel::new(3_000_00000000).unwrap(),
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), MwcbDeclineLevel::LEN);
+///
+/// let (_, parsed) = MwcbDeclineLevel::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+///
+/// // Levels have at most 6 whole number places.
+/// let mut bytes = bytes;
+/// bytes[11..19].copy_from_slice(&u64::MAX.to_be_bytes());
+/// assert!(MwcbDeclineLevel::parse(&bytes).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MwcbDeclineLevel {
+    pub header: Header,
+    pub level_1: MwcbLevel,
+    pub level_2: MwcbLevel,
+    pub level_3: MwcbLevel,
+}
+
+impl MwcbDeclineLevel {
+
+    pub const TAG: u8 = b'V';
+    pub const LEN: usize = 35;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, level_1) = field("level_1", input)?;
+        let (input, level_2) = field("level_2", input)?;
+        let (input, level_3) = field("level_3", input)?;
+
+        Ok((input, Self { header, level_1, level_2, level_3 }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.level_1.encode());
+        bytes.extend(self.level_2.encode());
+        bytes.extend(self.level_3.encode());
+        bytes
+    }
+}
+
+
+/// Informs that a MWCB level has been breached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MwcbStatus {
+    pub header: Header,
+    pub breached_level: BreachedLevel,
+}
+
+impl MwcbStatus {
+
+    pub const TAG: u8 = b'W';
+    pub const LEN: usize = 12;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, breached_level) = field("breached_level", input)?;
+
+        Ok((input, Self { header, breached_level }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.breached_level.encode());
+        bytes
+    }
+}
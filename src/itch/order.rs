@@ -0,0 +1,348 @@
+
+use crate::{
+    error::{ ParseError, field },
+    StockSymbol,
+    Mpid,
+    Price,
+    encode_bool,
+};
+
+use super::{ Header, Side, parse_tagged, encode_tagged };
+
+
+/// A new order has been accepted and added to the displayable book.
+/// (Not attributed to a market participant.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddOrder {
+    pub header: Header,
+    /// Unique reference number assigned to the new order.
+    pub order_reference_number: u64,
+    pub side: Side,
+    pub shares: u32,
+    pub stock: StockSymbol,
+    pub price: Price<u32, 4>,
+}
+
+impl AddOrder {
+
+    pub const TAG: u8 = b'A';
+    pub const LEN: usize = 36;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, shares) = field("shares", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, price) = field("price", input)?;
+
+        Ok((input, Self {
+            header,
+            order_reference_number,
+            side,
+            shares,
+            stock,
+            price,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.side.encode());
+        bytes.extend(self.shares.to_be_bytes());
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.price.encode());
+        bytes
+    }
+}
+
+
+/// A new order has been accepted and added to the displayable book,
+/// attributed to a market participant.
+/// ```
+/// use nsdq_util::{ NaiveTime, Price, StockSymbol, Mpid };
+/// use nsdq_util::itch::{ AddOrderWithMpid, Header, Side };
+///
+/// let msg = AddOrderWithMpid {
+///     header: Header {
+///         stock_locate: 1,
+///         tracking_number: 2,
+///         timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+///     },
+///     order_reference_number: 42,
+///     side: Side::Sell,
+///     shares: 100,
+///     stock: StockSymbol::from("AAPL").unwrap(),
+///     price: Price::new(150_0000).unwrap(),
+///     attribution: Mpid::from("FIRM").unwrap(),
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), AddOrderWithMpid::LEN);
+/// assert_eq!(&bytes[36..], b"FIRM");
+///
+/// let (_, parsed) = AddOrderWithMpid::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddOrderWithMpid {
+    pub header: Header,
+    /// Unique reference number assigned to the new order.
+    pub order_reference_number: u64,
+    pub side: Side,
+    pub shares: u32,
+    pub stock: StockSymbol,
+    pub price: Price<u32, 4>,
+    /// Market participant identifier associated with the entered order.
+    pub attribution: Mpid,
+}
+
+impl AddOrderWithMpid {
+
+    pub const TAG: u8 = b'F';
+    pub const LEN: usize = 40;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, shares) = field("shares", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, attribution) = field("attribution", input)?;
+
+        Ok((input, Self {
+            header,
+            order_reference_number,
+            side,
+            shares,
+            stock,
+            price,
+            attribution,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.side.encode());
+        bytes.extend(self.shares.to_be_bytes());
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.price.encode());
+        bytes.extend(self.attribution.encode());
+        bytes
+    }
+}
+
+
+/// An order on the book has been executed in whole or in part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExecuted {
+    pub header: Header,
+    pub order_reference_number: u64,
+    pub executed_shares: u32,
+    /// Nasdaq-generated day-unique match number of this execution.
+    pub match_number: u64,
+}
+
+impl OrderExecuted {
+
+    pub const TAG: u8 = b'E';
+    pub const LEN: usize = 31;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, executed_shares) = field("executed_shares", input)?;
+        let (input, match_number) = field("match_number", input)?;
+
+        Ok((input, Self {
+            header,
+            order_reference_number,
+            executed_shares,
+            match_number,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.executed_shares.to_be_bytes());
+        bytes.extend(self.match_number.to_be_bytes());
+        bytes
+    }
+}
+
+
+/// An order on the book has been executed at a price
+/// different from the initial display price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExecutedWithPrice {
+    pub header: Header,
+    pub order_reference_number: u64,
+    pub executed_shares: u32,
+    /// Nasdaq-generated day-unique match number of this execution.
+    pub match_number: u64,
+    /// Whether the execution should be reflected on time and sales
+    /// displays and volume calculations.
+    pub printable: bool,
+    pub execution_price: Price<u32, 4>,
+}
+
+impl OrderExecutedWithPrice {
+
+    pub const TAG: u8 = b'C';
+    pub const LEN: usize = 36;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, executed_shares) = field("executed_shares", input)?;
+        let (input, match_number) = field("match_number", input)?;
+        let (input, printable) = field("printable", input)?;
+        let (input, execution_price) = field("execution_price", input)?;
+
+        Ok((input, Self {
+            header,
+            order_reference_number,
+            executed_shares,
+            match_number,
+            printable,
+            execution_price,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.executed_shares.to_be_bytes());
+        bytes.extend(self.match_number.to_be_bytes());
+        bytes.extend(encode_bool(self.printable));
+        bytes.extend(self.execution_price.encode());
+        bytes
+    }
+}
+
+
+/// An order on the book is modified as a result of a partial cancellation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderCancel {
+    pub header: Header,
+    pub order_reference_number: u64,
+    /// Number of shares being removed from the display size of the order.
+    pub canceled_shares: u32,
+}
+
+impl OrderCancel {
+
+    pub const TAG: u8 = b'X';
+    pub const LEN: usize = 23;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, canceled_shares) = field("canceled_shares", input)?;
+
+        Ok((input, Self { header, order_reference_number, canceled_shares }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.canceled_shares.to_be_bytes());
+        bytes
+    }
+}
+
+
+/// An order on the book is being cancelled in full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderDelete {
+    pub header: Header,
+    pub order_reference_number: u64,
+}
+
+impl OrderDelete {
+
+    pub const TAG: u8 = b'D';
+    pub const LEN: usize = 19;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+
+        Ok((input, Self { header, order_reference_number }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes
+    }
+}
+
+
+/// An order on the book has been cancel-replaced.
+/// The original order is removed and the new order takes its side and stock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderReplace {
+    pub header: Header,
+    pub original_order_reference_number: u64,
+    pub new_order_reference_number: u64,
+    pub shares: u32,
+    pub price: Price<u32, 4>,
+}
+
+impl OrderReplace {
+
+    pub const TAG: u8 = b'U';
+    pub const LEN: usize = 35;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, original_order_reference_number) =
+            field("original_order_reference_number", input)?;
+        let (input, new_order_reference_number) =
+            field("new_order_reference_number", input)?;
+        let (input, shares) = field("shares", input)?;
+        let (input, price) = field("price", input)?;
+
+        Ok((input, Self {
+            header,
+            original_order_reference_number,
+            new_order_reference_number,
+            shares,
+            price,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.original_order_reference_number.to_be_bytes());
+        bytes.extend(self.new_order_reference_number.to_be_bytes());
+        bytes.extend(self.shares.to_be_bytes());
+        bytes.extend(self.price.encode());
+        bytes
+    }
+}
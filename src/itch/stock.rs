@@ -0,0 +1,411 @@
+
+use crate::{
+    error::{ ParseError, field },
+    StockSymbol,
+    FixStr4,
+    Mpid,
+    Price,
+    encode_bool,
+    encode_ternary,
+};
+
+use super::{
+    Header,
+    parse_tagged,
+    encode_tagged,
+    MarketCategory,
+    FinancialStatus,
+    IssueClassification,
+    IssueSubType,
+    Authenticity,
+    LuldTier,
+    TradingState,
+    RegShoAction,
+    MarketMakerMode,
+    MarketParticipantState,
+    IpoReleaseQualifier,
+    MarketCode,
+    HaltAction,
+};
+
+
+/// Disseminated at the start of each trading day for all active symbols.
+/// ```
+/// use nsdq_util::{ NaiveTime, StockSymbol };
+/// use nsdq_util::itch::*;
+///
+/// let msg = StockDirectory {
+///     header: Header {
+///         stock_locate: 1,
+///         tracking_number: 0,
+///         timestamp: NaiveTime::from_hms_opt(3, 0, 0).unwrap().into(),
+///     },
+///     stock: StockSymbol::from("ZVZZT").unwrap(),
+///     market_category: MarketCategory::NasdaqCapital,
+///     financial_status: FinancialStatus::Normal,
+///     round_lot_size: 100,
+///     round_lots_only: false,
+///     issue_classification: IssueClassification::CommonStock,
+///     issue_sub_type: IssueSubType::CommonShares,
+///     authenticity: Authenticity::Test,
+///     short_sale_threshold: Some(false),
+///     ipo_flag: None,
+///     luld_tier: LuldTier::Tier2,
+///     etp_flag: Some(false),
+///     etp_leverage_factor: 0,
+///     inverse: false,
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), StockDirectory::LEN);
+///
+/// let (_, parsed) = StockDirectory::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StockDirectory {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub market_category: MarketCategory,
+    pub financial_status: FinancialStatus,
+    /// Number of shares that represent a round lot.
+    pub round_lot_size: u32,
+    /// Whether Nasdaq accepts only round lot orders for this security.
+    pub round_lots_only: bool,
+    pub issue_classification: IssueClassification,
+    pub issue_sub_type: IssueSubType,
+    pub authenticity: Authenticity,
+    /// Whether the security is on the Reg SHO threshold list.
+    /// (`None` if not available.)
+    pub short_sale_threshold: Option<bool>,
+    /// Whether the security is set up for IPO release.
+    /// (`None` if not available.)
+    pub ipo_flag: Option<bool>,
+    pub luld_tier: LuldTier,
+    /// Whether the security is an Exchange Traded Product.
+    /// (`None` if not available.)
+    pub etp_flag: Option<bool>,
+    /// Tracks the integral relationship of the ETP to the underlying index.
+    pub etp_leverage_factor: u32,
+    /// Whether the ETP is an Inverse ETP.
+    pub inverse: bool,
+}
+
+impl StockDirectory {
+
+    pub const TAG: u8 = b'R';
+    pub const LEN: usize = 39;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, market_category) = field("market_category", input)?;
+        let (input, financial_status) = field("financial_status", input)?;
+        let (input, round_lot_size) = field("round_lot_size", input)?;
+        let (input, round_lots_only) = field("round_lots_only", input)?;
+        let (input, issue_classification) =
+            field("issue_classification", input)?;
+        let (input, issue_sub_type) = field("issue_sub_type", input)?;
+        let (input, authenticity) = field("authenticity", input)?;
+        let (input, short_sale_threshold) =
+            field("short_sale_threshold", input)?;
+        let (input, ipo_flag) = field("ipo_flag", input)?;
+        let (input, luld_tier) = field("luld_tier", input)?;
+        let (input, etp_flag) = field("etp_flag", input)?;
+        let (input, etp_leverage_factor) = field("etp_leverage_factor", input)?;
+        let (input, inverse) = field("inverse", input)?;
+
+        Ok((input, Self {
+            header,
+            stock,
+            market_category,
+            financial_status,
+            round_lot_size,
+            round_lots_only,
+            issue_classification,
+            issue_sub_type,
+            authenticity,
+            short_sale_threshold,
+            ipo_flag,
+            luld_tier,
+            etp_flag,
+            etp_leverage_factor,
+            inverse,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.market_category.encode());
+        bytes.extend(self.financial_status.encode());
+        bytes.extend(self.round_lot_size.to_be_bytes());
+        bytes.extend(encode_bool(self.round_lots_only));
+        bytes.extend(self.issue_classification.encode());
+        bytes.extend(self.issue_sub_type.encode());
+        bytes.extend(self.authenticity.encode());
+        bytes.extend(encode_ternary(self.short_sale_threshold));
+        bytes.extend(encode_ternary(self.ipo_flag));
+        bytes.extend(self.luld_tier.encode());
+        bytes.extend(encode_ternary(self.etp_flag));
+        bytes.extend(self.etp_leverage_factor.to_be_bytes());
+        bytes.extend(encode_bool(self.inverse));
+        bytes
+    }
+}
+
+
+/// Indicates the current trading status of a security to the trading
+/// community.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradingAction {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub trading_state: TradingState,
+    /// Trading action reason code, as published by Nasdaq.
+    pub reason: FixStr4,
+}
+
+impl TradingAction {
+
+    pub const TAG: u8 = b'H';
+    pub const LEN: usize = 25;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, trading_state) = field("trading_state", input)?;
+        // Reserved
+        let (input, _) = field::<u8>("reserved", input)?;
+        let (input, reason) = field("reason", input)?;
+
+        Ok((input, Self { header, stock, trading_state, reason }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.trading_state.encode());
+        bytes.push(b' ');
+        bytes.extend(self.reason.encode());
+        bytes
+    }
+}
+
+
+/// Indicates when a short sale price test restriction is in effect
+/// for a security, in accordance with Reg SHO.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegShoRestriction {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub action: RegShoAction,
+}
+
+impl RegShoRestriction {
+
+    pub const TAG: u8 = b'Y';
+    pub const LEN: usize = 20;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, action) = field("action", input)?;
+
+        Ok((input, Self { header, stock, action }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.action.encode());
+        bytes
+    }
+}
+
+
+/// Status of each Nasdaq market participant firm in an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MarketParticipantPosition {
+    pub header: Header,
+    pub mpid: Mpid,
+    pub stock: StockSymbol,
+    /// Whether the participant is a primary market maker.
+    pub primary_market_maker: bool,
+    pub market_maker_mode: MarketMakerMode,
+    pub market_participant_state: MarketParticipantState,
+}
+
+impl MarketParticipantPosition {
+
+    pub const TAG: u8 = b'L';
+    pub const LEN: usize = 26;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, mpid) = field("mpid", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, primary_market_maker) =
+            field("primary_market_maker", input)?;
+        let (input, market_maker_mode) = field("market_maker_mode", input)?;
+        let (input, market_participant_state) =
+            field("market_participant_state", input)?;
+
+        Ok((input, Self {
+            header,
+            mpid,
+            stock,
+            primary_market_maker,
+            market_maker_mode,
+            market_participant_state,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.mpid.encode());
+        bytes.extend(self.stock.encode());
+        bytes.extend(encode_bool(self.primary_market_maker));
+        bytes.extend(self.market_maker_mode.encode());
+        bytes.extend(self.market_participant_state.encode());
+        bytes
+    }
+}
+
+
+/// Anticipated IPO quotation release time of a security.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpoQuotingPeriod {
+    pub header: Header,
+    pub stock: StockSymbol,
+    /// Anticipated quotation release time, in seconds since midnight.
+    pub release_time: u32,
+    pub release_qualifier: IpoReleaseQualifier,
+    pub ipo_price: Price<u32, 4>,
+}
+
+impl IpoQuotingPeriod {
+
+    pub const TAG: u8 = b'K';
+    pub const LEN: usize = 28;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, release_time) = field("release_time", input)?;
+        let (input, release_qualifier) = field("release_qualifier", input)?;
+        let (input, ipo_price) = field("ipo_price", input)?;
+
+        Ok((input, Self {
+            header,
+            stock,
+            release_time,
+            release_qualifier,
+            ipo_price,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.release_time.to_be_bytes());
+        bytes.extend(self.release_qualifier.encode());
+        bytes.extend(self.ipo_price.encode());
+        bytes
+    }
+}
+
+
+/// Auction collar thresholds within which a paused security can reopen
+/// following a LULD Trading Pause.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuldAuctionCollar {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub reference_price: Price<u32, 4>,
+    pub upper_collar_price: Price<u32, 4>,
+    pub lower_collar_price: Price<u32, 4>,
+    /// Number of extensions to the reopening auction.
+    pub collar_extension: u32,
+}
+
+impl LuldAuctionCollar {
+
+    pub const TAG: u8 = b'J';
+    pub const LEN: usize = 35;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, reference_price) = field("reference_price", input)?;
+        let (input, upper_collar_price) = field("upper_collar_price", input)?;
+        let (input, lower_collar_price) = field("lower_collar_price", input)?;
+        let (input, collar_extension) = field("collar_extension", input)?;
+
+        Ok((input, Self {
+            header,
+            stock,
+            reference_price,
+            upper_collar_price,
+            lower_collar_price,
+            collar_extension,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.reference_price.encode());
+        bytes.extend(self.upper_collar_price.encode());
+        bytes.extend(self.lower_collar_price.encode());
+        bytes.extend(self.collar_extension.to_be_bytes());
+        bytes
+    }
+}
+
+
+/// Operational halt of a security on a single Nasdaq market.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationalHalt {
+    pub header: Header,
+    pub stock: StockSymbol,
+    pub market_code: MarketCode,
+    pub action: HaltAction,
+}
+
+impl OperationalHalt {
+
+    pub const TAG: u8 = b'h';
+    pub const LEN: usize = 21;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, market_code) = field("market_code", input)?;
+        let (input, action) = field("action", input)?;
+
+        Ok((input, Self { header, stock, market_code, action }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.market_code.encode());
+        bytes.extend(self.action.encode());
+        bytes
+    }
+}
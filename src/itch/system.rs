@@ -0,0 +1,53 @@
+
+use crate::error::{ ParseError, field };
+
+use super::{ Header, EventCode, parse_tagged, encode_tagged };
+
+
+/// Signals a market or data feed handler event.
+/// ```
+/// use nsdq_util::NaiveTime;
+/// use nsdq_util::itch::{ SystemEvent, Header, EventCode };
+///
+/// let msg = SystemEvent {
+///     header: Header {
+///         stock_locate: 0,
+///         tracking_number: 0,
+///         timestamp: NaiveTime::from_hms_opt(4, 0, 0).unwrap().into(),
+///     },
+///     event_code: EventCode::StartOfSystemHours,
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), SystemEvent::LEN);
+/// assert_eq!(&bytes[5..11], &14_400_000_000_000u64.to_be_bytes()[2..]);
+///
+/// let (_, parsed) = SystemEvent::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemEvent {
+    pub header: Header,
+    pub event_code: EventCode,
+}
+
+impl SystemEvent {
+
+    pub const TAG: u8 = b'S';
+    pub const LEN: usize = 12;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, event_code) = field("event_code", input)?;
+
+        Ok((input, Self { header, event_code }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.event_code.encode());
+        bytes
+    }
+}
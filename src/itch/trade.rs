@@ -0,0 +1,166 @@
+
+use crate::{
+    error::{ ParseError, field },
+    StockSymbol,
+    Price,
+};
+
+use super::{ Header, Side, CrossType, parse_tagged, encode_tagged };
+
+
+/// Execution details for a match involving a non-displayable order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trade {
+    pub header: Header,
+    /// Always 0 since Nasdaq no longer discloses non-displayed order details.
+    pub order_reference_number: u64,
+    pub side: Side,
+    pub shares: u32,
+    pub stock: StockSymbol,
+    pub price: Price<u32, 4>,
+    /// Nasdaq-generated day-unique match number of this execution.
+    pub match_number: u64,
+}
+
+impl Trade {
+
+    pub const TAG: u8 = b'P';
+    pub const LEN: usize = 44;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, shares) = field("shares", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, match_number) = field("match_number", input)?;
+
+        Ok((input, Self {
+            header,
+            order_reference_number,
+            side,
+            shares,
+            stock,
+            price,
+            match_number,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.order_reference_number.to_be_bytes());
+        bytes.extend(self.side.encode());
+        bytes.extend(self.shares.to_be_bytes());
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.price.encode());
+        bytes.extend(self.match_number.to_be_bytes());
+        bytes
+    }
+}
+
+
+/// Bulk print of shares executed in a Nasdaq cross.
+/// ```
+/// use nsdq_util::{ NaiveTime, Price, StockSymbol };
+/// use nsdq_util::itch::{ CrossTrade, CrossType, Header };
+///
+/// let msg = CrossTrade {
+///     header: Header {
+///         stock_locate: 1,
+///         tracking_number: 0,
+///         timestamp: NaiveTime::from_hms_opt(16, 0, 0).unwrap().into(),
+///     },
+///     shares: 1_000_000,
+///     stock: StockSymbol::from("AAPL").unwrap(),
+///     cross_price: Price::new(150_0000).unwrap(),
+///     match_number: 7,
+///     cross_type: CrossType::Closing,
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), CrossTrade::LEN);
+/// assert_eq!(bytes[39], b'C');
+///
+/// let (_, parsed) = CrossTrade::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CrossTrade {
+    pub header: Header,
+    pub shares: u64,
+    pub stock: StockSymbol,
+    pub cross_price: Price<u32, 4>,
+    /// Nasdaq-generated day-unique match number of this execution.
+    pub match_number: u64,
+    pub cross_type: CrossType,
+}
+
+impl CrossTrade {
+
+    pub const TAG: u8 = b'Q';
+    pub const LEN: usize = 40;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, shares) = field("shares", input)?;
+        let (input, stock) = field("stock", input)?;
+        let (input, cross_price) = field("cross_price", input)?;
+        let (input, match_number) = field("match_number", input)?;
+        let (input, cross_type) = field("cross_type", input)?;
+
+        Ok((input, Self {
+            header,
+            shares,
+            stock,
+            cross_price,
+            match_number,
+            cross_type,
+        }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.shares.to_be_bytes());
+        bytes.extend(self.stock.encode());
+        bytes.extend(self.cross_price.encode());
+        bytes.extend(self.match_number.to_be_bytes());
+        bytes.extend(self.cross_type.encode());
+        bytes
+    }
+}
+
+
+/// An execution has been broken for an erroneous trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrokenTrade {
+    pub header: Header,
+    /// Match number of the execution being broken.
+    pub match_number: u64,
+}
+
+impl BrokenTrade {
+
+    pub const TAG: u8 = b'B';
+    pub const LEN: usize = 19;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, header) = parse_tagged(Self::TAG, input)?;
+        let (input, match_number) = field("match_number", input)?;
+
+        Ok((input, Self { header, match_number }))
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = encode_tagged(Self::TAG, &self.header, Self::LEN);
+        bytes.extend(self.match_number.to_be_bytes());
+        bytes
+    }
+}
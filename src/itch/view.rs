@@ -0,0 +1,396 @@
+
+use crate::{
+    Wire,
+    error::{ ParseError, field, field_with },
+    Timestamp,
+    StockSymbol,
+    Mpid,
+    FixStr4,
+    Price,
+    MwcbLevel,
+};
+
+use super::*;
+
+
+/// Generates borrowed views over the raw bytes of each message type,
+/// and the dispatching `ItchView` enum.
+/// Offsets count from the message type byte.
+/// `values` always decode once the length is checked;
+/// `codes` return `None` if the bytes are not a recognized code
+/// or are out of range.
+macro_rules! itch_views {
+    ($(
+        $(#[$attr:meta])*
+        $view:ident => $kind:ident;
+            values { $($field:ident: $ty:ty [$at:expr]),*$(,)? }
+            codes { $($code:ident: $cty:ty [$cat:expr]),*$(,)? }
+    )*) => {
+
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $view<'a> {
+                bytes: &'a [u8],
+            }
+
+            impl<'a> $view<'a> {
+
+                /// Check the type byte and length, without decoding fields.
+                pub fn parse(
+                    input: &'a [u8]
+                ) -> nom::IResult<&'a [u8], Self, ParseError> {
+
+                    field_with(
+                        "message_type",
+                        Some(1),
+                        |input| message_type($kind::TAG, input),
+                        input
+                    )?;
+
+                    let (rest, bytes) = field_with(
+                        stringify!($kind),
+                        Some($kind::LEN),
+                        nom::bytes::streaming::take($kind::LEN),
+                        input
+                    )?;
+                    Ok((rest, Self { bytes }))
+                }
+
+                /// The raw message, including its type byte.
+                pub fn as_bytes(&self) -> &'a [u8] { self.bytes }
+
+                /// Decode all fields into the owned message.
+                pub fn decode(&self) -> Result<$kind, nom::Err<ParseError>> {
+                    $kind::parse(self.bytes).map(|(_, msg)| msg)
+                }
+
+                pub fn stock_locate(&self) -> u16 {
+                    u16::from_be_bytes([self.bytes[1], self.bytes[2]])
+                }
+
+                pub fn tracking_number(&self) -> u16 {
+                    u16::from_be_bytes([self.bytes[3], self.bytes[4]])
+                }
+
+                /// Raw timestamp, as nanoseconds since midnight.
+                pub fn nanos(&self) -> u64 { self.timestamp().nanos() }
+
+                pub fn timestamp(&self) -> Timestamp {
+                    let (_, time) = Timestamp::parse_itch(&self.bytes[5..])
+                        .expect("Length is checked when parsed");
+                    time
+                }
+
+                $(
+                    pub fn $field(&self) -> $ty {
+                        value::<$ty>(&self.bytes[$at..])
+                            .expect("Length is checked when parsed")
+                    }
+                )*
+
+                $(
+                    pub fn $code(&self) -> Option<$cty> {
+                        value::<$cty>(&self.bytes[$cat..])
+                    }
+                )*
+            }
+        )*
+
+        /// Borrowed view of any ITCH 5.0 message,
+        /// dispatched on the message type byte.
+        /// ```
+        /// use nsdq_util::{ NaiveTime, Price, StockSymbol };
+        /// use nsdq_util::itch::{ ItchView, AddOrder, OrderDelete, Header, Side };
+        ///
+        /// let header = Header {
+        ///     stock_locate: 1,
+        ///     tracking_number: 0,
+        ///     timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+        /// };
+        /// let mut feed = AddOrder {
+        ///     header,
+        ///     order_reference_number: 42,
+        ///     side: Side::Buy,
+        ///     shares: 100,
+        ///     stock: StockSymbol::from("AAPL").unwrap(),
+        ///     price: Price::new(150_0000).unwrap(),
+        /// }.encode();
+        /// feed.extend(OrderDelete { header, order_reference_number: 42 }.encode());
+        ///
+        /// let aapl = StockSymbol::from("AAPL").unwrap();
+        /// let mut input = &feed[..];
+        /// let mut adds = vec![];
+        /// while !input.is_empty() {
+        ///     let (rest, view) = ItchView::parse(input).unwrap();
+        ///     if let ItchView::AddOrder(add) = view {
+        ///         if add.stock() == aapl { adds.push(add.decode().unwrap()) }
+        ///     }
+        ///     input = rest;
+        /// }
+        ///
+        /// assert_eq!(adds.len(), 1);
+        /// assert_eq!(adds[0].order_reference_number, 42);
+        /// assert!(ItchView::parse(b"?").is_err());
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum ItchView<'a> {$(
+            $kind($view<'a>),
+        )*}
+
+        impl<'a> ItchView<'a> {
+
+            /// Check the type byte and length of any message,
+            /// without decoding fields.
+            pub fn parse(
+                input: &'a [u8]
+            ) -> nom::IResult<&'a [u8], Self, ParseError> {
+
+                let (_, tag) = field::<u8>("message_type", input)?;
+                match tag {
+                    $(
+                        $kind::TAG => {
+                            let (input, view) = $view::parse(input)?;
+                            Ok((input, ItchView::$kind(view)))
+                        },
+                    )*
+                    _ => Err(nom::Err::Error(ParseError::unknown_message(input))),
+                }
+            }
+
+            /// Byte identifying the message type.
+            pub fn message_type(&self) -> u8 {
+                match self {$(
+                    ItchView::$kind(_) => $kind::TAG,
+                )*}
+            }
+
+            /// The raw message, including its type byte.
+            pub fn as_bytes(&self) -> &'a [u8] {
+                match self {$(
+                    ItchView::$kind(view) => view.as_bytes(),
+                )*}
+            }
+
+            pub fn stock_locate(&self) -> u16 {
+                match self {$(
+                    ItchView::$kind(view) => view.stock_locate(),
+                )*}
+            }
+
+            /// Decode all fields into the owned message.
+            pub fn decode(&self) -> Result<ItchMessage, nom::Err<ParseError>> {
+                ItchMessage::parse(self.as_bytes()).map(|(_, msg)| msg)
+            }
+        }
+    }
+}
+
+/// Decode a field from the front of the bytes.
+fn value<T: Wire>(bytes: &[u8]) -> Option<T> {
+    T::parse(bytes).ok().map(|(_, val)| val)
+}
+
+
+itch_views!{
+
+    SystemEventView => SystemEvent;
+        values {}
+        codes { event_code: EventCode [11] }
+
+    StockDirectoryView => StockDirectory;
+        values {
+            stock: StockSymbol [11],
+            round_lot_size: u32 [21],
+            etp_leverage_factor: u32 [34],
+        }
+        codes {
+            market_category: MarketCategory [19],
+            financial_status: FinancialStatus [20],
+            round_lots_only: bool [25],
+            issue_classification: IssueClassification [26],
+            issue_sub_type: IssueSubType [27],
+            authenticity: Authenticity [29],
+            short_sale_threshold: Option<bool> [30],
+            ipo_flag: Option<bool> [31],
+            luld_tier: LuldTier [32],
+            etp_flag: Option<bool> [33],
+            inverse: bool [38],
+        }
+
+    TradingActionView => TradingAction;
+        values { stock: StockSymbol [11], reason: FixStr4 [21] }
+        codes { trading_state: TradingState [19] }
+
+    RegShoRestrictionView => RegShoRestriction;
+        values { stock: StockSymbol [11] }
+        codes { action: RegShoAction [19] }
+
+    MarketParticipantPositionView => MarketParticipantPosition;
+        values { mpid: Mpid [11], stock: StockSymbol [15] }
+        codes {
+            primary_market_maker: bool [23],
+            market_maker_mode: MarketMakerMode [24],
+            market_participant_state: MarketParticipantState [25],
+        }
+
+    MwcbDeclineLevelView => MwcbDeclineLevel;
+        values {}
+        codes {
+            level_1: MwcbLevel [11],
+            level_2: MwcbLevel [19],
+            level_3: MwcbLevel [27],
+        }
+
+    MwcbStatusView => MwcbStatus;
+        values {}
+        codes { breached_level: BreachedLevel [11] }
+
+    IpoQuotingPeriodView => IpoQuotingPeriod;
+        values {
+            stock: StockSymbol [11],
+            release_time: u32 [19],
+            ipo_price: Price<u32, 4> [24],
+        }
+        codes { release_qualifier: IpoReleaseQualifier [23] }
+
+    LuldAuctionCollarView => LuldAuctionCollar;
+        values {
+            stock: StockSymbol [11],
+            reference_price: Price<u32, 4> [19],
+            upper_collar_price: Price<u32, 4> [23],
+            lower_collar_price: Price<u32, 4> [27],
+            collar_extension: u32 [31],
+        }
+        codes {}
+
+    OperationalHaltView => OperationalHalt;
+        values { stock: StockSymbol [11] }
+        codes { market_code: MarketCode [19], action: HaltAction [20] }
+
+    /// Borrowed view of an `AddOrder`, decoding fields on access.
+    /// ```
+    /// use nsdq_util::{ NaiveTime, Price, StockSymbol };
+    /// use nsdq_util::itch::{ AddOrder, AddOrderView, Header, Side };
+    ///
+    /// let bytes = AddOrder {
+    ///     header: Header {
+    ///         stock_locate: 7,
+    ///         tracking_number: 0,
+    ///         timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+    ///     },
+    ///     order_reference_number: 42,
+    ///     side: Side::Sell,
+    ///     shares: 100,
+    ///     stock: StockSymbol::from("AAPL").unwrap(),
+    ///     price: Price::new(150_0000).unwrap(),
+    /// }.encode();
+    ///
+    /// let (rest, view) = AddOrderView::parse(&bytes).unwrap();
+    /// assert!(rest.is_empty());
+    /// assert_eq!(view.stock_locate(), 7);
+    /// assert_eq!(view.nanos(), 34_200_000_000_000);
+    /// assert_eq!(view.stock().to_str(), "AAPL");
+    /// assert_eq!(view.price(), Price::new(150_0000).unwrap());
+    /// assert_eq!(view.side(), Some(Side::Sell));
+    ///
+    /// assert!(AddOrderView::parse(&bytes[..20]).is_err());
+    /// ```
+    AddOrderView => AddOrder;
+        values {
+            order_reference_number: u64 [11],
+            shares: u32 [20],
+            stock: StockSymbol [24],
+            price: Price<u32, 4> [32],
+        }
+        codes { side: Side [19] }
+
+    AddOrderWithMpidView => AddOrderWithMpid;
+        values {
+            order_reference_number: u64 [11],
+            shares: u32 [20],
+            stock: StockSymbol [24],
+            price: Price<u32, 4> [32],
+            attribution: Mpid [36],
+        }
+        codes { side: Side [19] }
+
+    OrderExecutedView => OrderExecuted;
+        values {
+            order_reference_number: u64 [11],
+            executed_shares: u32 [19],
+            match_number: u64 [23],
+        }
+        codes {}
+
+    OrderExecutedWithPriceView => OrderExecutedWithPrice;
+        values {
+            order_reference_number: u64 [11],
+            executed_shares: u32 [19],
+            match_number: u64 [23],
+            execution_price: Price<u32, 4> [32],
+        }
+        codes { printable: bool [31] }
+
+    OrderCancelView => OrderCancel;
+        values {
+            order_reference_number: u64 [11],
+            canceled_shares: u32 [19],
+        }
+        codes {}
+
+    OrderDeleteView => OrderDelete;
+        values { order_reference_number: u64 [11] }
+        codes {}
+
+    OrderReplaceView => OrderReplace;
+        values {
+            original_order_reference_number: u64 [11],
+            new_order_reference_number: u64 [19],
+            shares: u32 [27],
+            price: Price<u32, 4> [31],
+        }
+        codes {}
+
+    TradeView => Trade;
+        values {
+            order_reference_number: u64 [11],
+            shares: u32 [20],
+            stock: StockSymbol [24],
+            price: Price<u32, 4> [32],
+            match_number: u64 [36],
+        }
+        codes { side: Side [19] }
+
+    CrossTradeView => CrossTrade;
+        values {
+            shares: u64 [11],
+            stock: StockSymbol [19],
+            cross_price: Price<u32, 4> [27],
+            match_number: u64 [31],
+        }
+        codes { cross_type: CrossType [39] }
+
+    BrokenTradeView => BrokenTrade;
+        values { match_number: u64 [11] }
+        codes {}
+
+    NetOrderImbalanceView => NetOrderImbalance;
+        values {
+            paired_shares: u64 [11],
+            imbalance_shares: u64 [19],
+            stock: StockSymbol [28],
+            far_price: Price<u32, 4> [36],
+            near_price: Price<u32, 4> [40],
+            current_reference_price: Price<u32, 4> [44],
+        }
+        codes {
+            imbalance_direction: ImbalanceDirection [27],
+            cross_type: CrossType [48],
+            price_variation: PriceVariation [49],
+        }
+
+    RetailPriceImprovementView => RetailPriceImprovement;
+        values { stock: StockSymbol [11] }
+        codes { interest_flag: InterestFlag [19] }
+}
@@ -1,7 +1,22 @@
 
+
 pub mod error;
 
+pub mod soupbin;
+pub mod moldudp64;
+pub mod itch;
+pub mod ouch;
+pub mod calendar;
+pub mod codes;
+
 pub mod types;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use nom;
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
 pub use types::{
 
     string::{
@@ -12,7 +27,18 @@ pub use types::{
         StockSymbol,
     },
 
-    price::Price,
+    price::{ Price, Rounding },
+    dyn_price::DynPrice,
+    notional::Notional,
+
+    domain::{
+        OuchOrderPrice,
+        MwcbLevel,
+        PegOffset,
+        PriceOrMarket,
+    },
+
+    wire::{ Wire, Writer },
 
     time::{
         parse_ouch_time,
@@ -20,7 +46,11 @@ pub use types::{
         encode_ouch_time,
         parse_itch_time,
         parse_itch_time_bold,
+        encode_itch_time,
+        encode_itch_nanos,
         NaiveTime,
+        NaiveDate,
+        Timestamp,
     },
 
     bools::{
@@ -35,3 +65,63 @@ pub use types::{
     },
 };
 
+#[cfg(feature = "tz")]
+pub use types::time::{ EASTERN, DateTime, Utc };
+
+/// Derive `LEN`, `parse`, `encode`, and `Wire` for a message struct,
+/// encoding its fields in declaration order.
+/// Fields use their `Wire` implementation unless given an attribute:
+/// `#[bool(yes = 'R', no = 'X')]` or `#[ternary(..)]` for custom character
+/// mappings, and `#[timestamp(itch)]` or `#[timestamp(ouch)]` for the
+/// 6-byte or 8-byte time of day.
+/// `#[tag(b'A')]` on the struct adds the message type byte.
+/// ```
+/// use nsdq_util::{ NsdqMessage, NaiveTime, Price, StockSymbol, Timestamp, Wire };
+///
+/// #[derive(NsdqMessage, Debug, PartialEq)]
+/// #[tag(b'Z')]
+/// struct Quote {
+///     #[timestamp(itch)]
+///     timestamp: Timestamp,
+///     symbol: StockSymbol,
+///     price: Price<u32, 4>,
+///     #[bool(yes = 'R', no = 'X')]
+///     regular: bool,
+///     #[ternary]
+///     halted: Option<bool>,
+/// }
+///
+/// assert_eq!(Quote::LEN, 1 + 6 + 8 + 4 + 1 + 1);
+/// assert_eq!(<Quote as Wire>::LEN, Quote::LEN);
+///
+/// let quote = Quote {
+///     timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+///     symbol: StockSymbol::from("AAPL").unwrap(),
+///     price: Price::new(1_500_000).unwrap(),
+///     regular: false,
+///     halted: None,
+/// };
+///
+/// let bytes = quote.encode();
+/// assert_eq!(bytes[0], b'Z');
+/// assert_eq!(bytes[19], b'X');
+/// assert_eq!(bytes[20], b' ');
+///
+/// let (rest, parsed) = Quote::parse(&bytes).unwrap();
+/// assert!(rest.is_empty());
+/// assert_eq!(parsed, quote);
+///
+/// assert!(Quote::parse(b"A").is_err());
+///
+/// let mut bad = bytes.clone();
+/// bad[19] = b'?';
+/// match Quote::parse(&bad) {
+///     Err(nom::Err::Error(err)) => {
+///         assert_eq!(err.message(), Some("Quote"));
+///         assert_eq!(err.field, Some("regular"));
+///         assert_eq!(err.offset(), Some(19));
+///     },
+///     _ => panic!("'?' is not a valid flag"),
+/// }
+/// ```
+pub use nsdq_util_derive::NsdqMessage;
@@ -0,0 +1,294 @@
+
+use nom::{
+    number::complete::{ be_u16, be_u64 },
+    bytes::complete::take,
+};
+
+use crate::{
+    Wire,
+    types::wire::write_bytes,
+    define_str,
+    error::TypeError,
+    types::string::helper,
+};
+
+
+define_str!{
+    Session [10usize]
+        "MoldUDP64 session, identifying the stream of messages \
+        that sequence numbers refer to."
+}
+
+impl Session {
+
+    /// Create a session from up to 10 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::moldudp64::Session;
+    ///
+    /// assert!(Session::from("000012345A").is_ok());
+    /// assert!(Session::from("SESSION-1").is_err());
+    /// assert!(Session::from("SESSION0001").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 10 && helper::is_alphanumeric(s) {
+            Ok(Session(helper::fixed_str::<10>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("Session"),
+                s.to_string()
+            ))
+        }
+    }
+}
+
+
+/// Message count used by the server to signal the end of a session.
+pub const END_OF_SESSION: u16 = 0xFFFF;
+
+/// Leading 20 bytes of every downstream MoldUDP64 packet.
+/// ```
+/// use nsdq_util::moldudp64::{ Header, Packet, END_OF_SESSION };
+///
+/// let mut datagram = b"SESSION001".to_vec();
+/// datagram.extend(8u64.to_be_bytes());
+/// datagram.extend(0u16.to_be_bytes());
+///
+/// let heartbeat = Packet::parse(&datagram).unwrap();
+/// assert!(heartbeat.is_heartbeat());
+/// assert_eq!(heartbeat.messages().count(), 0);
+/// assert_eq!(heartbeat.header.encode().to_vec(), datagram);
+///
+/// datagram[18..].copy_from_slice(&END_OF_SESSION.to_be_bytes());
+/// let (_, header) = Header::parse(&datagram).unwrap();
+/// assert!(header.is_end_of_session());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub session: Session,
+    /// Sequence number of the first message in the packet.
+    /// (For heartbeats, the sequence number of the next message to expect.)
+    pub sequence_number: u64,
+    pub message_count: u16,
+}
+
+impl Header {
+
+    /// Size of the header in bytes.
+    pub const LEN: usize = 20;
+
+    /// Heartbeats carry no messages.
+    pub fn is_heartbeat(&self) -> bool { self.message_count == 0 }
+
+    /// The server will send no more messages for this session.
+    pub fn is_end_of_session(&self) -> bool {
+        self.message_count == END_OF_SESSION
+    }
+
+    /// Parse the header from 20 bytes.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, session) = Session::parse(input)?;
+        let (input, sequence_number) = be_u64(input)?;
+        let (input, message_count) = be_u16(input)?;
+
+        Ok((input, Self { session, sequence_number, message_count }))
+    }
+
+    /// Encode the header as 20 bytes.
+    pub fn encode(&self) -> [u8; 20] {
+
+        let mut bytes = [0u8; 20];
+        bytes[..10].copy_from_slice(&self.session.encode());
+        bytes[10..18].copy_from_slice(&self.sequence_number.to_be_bytes());
+        bytes[18..].copy_from_slice(&self.message_count.to_be_bytes());
+        bytes
+    }
+}
+
+impl Wire for Header {
+    const LEN: usize = Header::LEN;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Header::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
+}
+
+
+/// A downstream MoldUDP64 datagram, borrowing its message blocks.
+/// ```
+/// use nsdq_util::moldudp64::{ Packet, Session };
+///
+/// let mut datagram = b"SESSION001".to_vec();
+/// datagram.extend(7u64.to_be_bytes());
+/// datagram.extend(2u16.to_be_bytes());
+/// datagram.extend(3u16.to_be_bytes());
+/// datagram.extend(b"abc");
+/// datagram.extend(1u16.to_be_bytes());
+/// datagram.extend(b"d");
+///
+/// let packet = Packet::parse(&datagram).unwrap();
+/// assert_eq!(packet.header.session, Session::from("SESSION001").unwrap());
+/// assert_eq!(packet.header.sequence_number, 7);
+///
+/// let messages: Vec<(u64, &[u8])> = packet.sequenced().collect();
+/// assert_eq!(messages, vec![(7, &b"abc"[..]), (8, &b"d"[..])]);
+///
+/// // Message blocks must agree with the header's message count.
+/// assert!(Packet::parse(&datagram[..datagram.len() - 1]).is_err());
+///
+/// // Sequence numbers past `u64::MAX` are not yielded.
+/// datagram[10..18].copy_from_slice(&u64::MAX.to_be_bytes());
+/// let packet = Packet::parse(&datagram).unwrap();
+/// assert_eq!(packet.sequenced().count(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet<'a> {
+    pub header: Header,
+    blocks: &'a [u8],
+}
+
+impl<'a> Packet<'a> {
+
+    /// Parse a complete datagram.
+    /// Message blocks are validated against the header's message count,
+    /// but are not copied.
+    pub fn parse(
+        datagram: &'a [u8]
+    ) -> Result<Self, nom::Err<nom::error::Error<&'a [u8]>>> {
+
+        let (blocks, header) = Header::parse(datagram)?;
+
+        let count = if header.is_end_of_session() {
+            0
+        } else {
+            header.message_count
+        };
+
+        let mut input = blocks;
+        for _ in 0..count {
+            let (rest, len) = be_u16(input)?;
+            let (rest, _) = take(len as usize)(rest)?;
+            input = rest;
+        }
+
+        if !input.is_empty() {
+            return Err(nom::Err::Error(
+                nom::error::Error::new(input, nom::error::ErrorKind::Eof)
+            ))
+        }
+
+        Ok(Self { header, blocks })
+    }
+
+    /// Heartbeats carry no messages.
+    pub fn is_heartbeat(&self) -> bool { self.header.is_heartbeat() }
+
+    /// The server will send no more messages for this session.
+    pub fn is_end_of_session(&self) -> bool {
+        self.header.is_end_of_session()
+    }
+
+    /// Iterate over the message payloads in the packet.
+    pub fn messages(&self) -> Messages<'a> {
+        Messages { blocks: self.blocks }
+    }
+
+    /// Iterate over the message payloads with their sequence numbers.
+    /// Stops early if a sequence number would overflow `u64`.
+    pub fn sequenced(&self) -> impl Iterator<Item = (u64, &'a [u8])> + use<'a> {
+        let first = self.header.sequence_number;
+        self.messages()
+            .enumerate()
+            .map_while(move |(i, msg)| Some((first.checked_add(i as u64)?, msg)))
+    }
+}
+
+/// Zero-copy iterator over the message blocks of a packet.
+#[derive(Debug, Clone)]
+pub struct Messages<'a> {
+    blocks: &'a [u8],
+}
+
+impl<'a> Iterator for Messages<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        if self.blocks.len() < 2 {
+            return None
+        }
+
+        let (len, rest) = self.blocks.split_at(2);
+        let len = u16::from_be_bytes([len[0], len[1]]) as usize;
+        if rest.len() < len {
+            return None
+        }
+
+        let (msg, rest) = rest.split_at(len);
+        self.blocks = rest;
+        Some(msg)
+    }
+}
+
+
+/// Sent by the client to request retransmission of missed messages.
+/// ```
+/// use nsdq_util::moldudp64::{ Request, Session };
+///
+/// let request = Request {
+///     session: Session::from("SESSION001").unwrap(),
+///     sequence_number: 1000,
+///     message_count: 25,
+/// };
+///
+/// let bytes = request.encode();
+/// assert_eq!(&bytes[..10], b"SESSION001");
+/// assert_eq!(&bytes[10..18], &1000u64.to_be_bytes());
+/// assert_eq!(&bytes[18..], &25u16.to_be_bytes());
+///
+/// let (_, parsed) = Request::parse(&bytes).unwrap();
+/// assert_eq!(parsed, request);
+/// assert_eq!(bytes.len(), Request::LEN);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Request {
+    pub session: Session,
+    /// First sequence number requested.
+    pub sequence_number: u64,
+    /// Number of messages requested.
+    pub message_count: u16,
+}
+
+impl Request {
+
+    /// Size of the request in bytes.
+    pub const LEN: usize = 20;
+
+    /// Parse a request packet from 20 bytes.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, header) = Header::parse(input)?;
+        let Header { session, sequence_number, message_count } = header;
+
+        Ok((input, Self { session, sequence_number, message_count }))
+    }
+
+    /// Encode the request packet as 20 bytes.
+    pub fn encode(&self) -> [u8; 20] {
+        Header {
+            session: self.session,
+            sequence_number: self.sequence_number,
+            message_count: self.message_count,
+        }.encode()
+    }
+}
+
+impl Wire for Request {
+    const LEN: usize = Request::LEN;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Request::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
+}
@@ -0,0 +1,287 @@
+
+pub mod codes;
+pub mod appendage;
+pub mod inbound;
+pub mod outbound;
+
+pub use codes::*;
+pub use appendage::{
+    OptionalAppendage,
+    TagValue,
+    Route,
+    CustomerType,
+    PriceType,
+    PostOnly,
+    BboWeightIndicator,
+};
+pub use inbound::{
+    EnterOrder,
+    ReplaceOrder,
+    CancelOrder,
+    ModifyOrder,
+    MassCancel,
+    DisableOrderEntry,
+    EnableOrderEntry,
+    AccountQuery,
+};
+pub use outbound::{
+    SystemEvent,
+    OrderAccepted,
+    OrderReplaced,
+    OrderCanceled,
+    AiqCanceled,
+    OrderExecuted,
+    BrokenTrade,
+    Rejected,
+    CancelPending,
+    CancelReject,
+    OrderPriorityUpdate,
+    OrderModified,
+    OrderRestated,
+    AccountQueryResponse,
+};
+
+use nom::{
+    number::streaming::be_u8,
+    error::ContextError,
+};
+
+use crate::{
+    define_str,
+    error::{ TypeError, ParseError, field, field_with },
+    types::string::helper,
+};
+
+
+define_str!{
+    ClOrdId [14usize]
+        "Customer order identifier, padded on the right with spaces. \
+        Nasdaq does not validate uniqueness."
+}
+
+impl Default for ClOrdId {
+    fn default() -> Self { ClOrdId(*b"              ") }
+}
+
+impl ClOrdId {
+
+    /// Create an order identifier from up to 14 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::ouch::ClOrdId;
+    ///
+    /// assert!(ClOrdId::from("ORDER 0001").is_ok());
+    /// assert!(ClOrdId::from("ORDER-0001").is_err());
+    /// assert!(ClOrdId::from("ORDER0000000001").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 14 && helper::is_alphanumeric(s) {
+            Ok(ClOrdId(helper::fixed_str::<14>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("ClOrdId"),
+                s.to_string()
+            ))
+        }
+    }
+}
+
+
+/// Generates a dispatching enum over a set of message types.
+macro_rules! ouch_messages {
+    ($(#[$attr:meta])* $name:ident; $($kind:ident),*$(,)?) => {
+
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {$(
+            $kind($kind),
+        )*}
+
+        impl $name {
+
+            /// Byte identifying the message type.
+            pub fn message_type(&self) -> u8 {
+                match self {$(
+                    $name::$kind(_) => $kind::TAG,
+                )*}
+            }
+
+            /// Parse any message, starting with its type byte.
+            /// Errors name the message type as their context.
+            pub fn parse(
+                input: &[u8]
+            ) -> nom::IResult<&[u8], Self, ParseError> {
+
+                let (_, tag) = field::<u8>("message_type", input)?;
+                match tag {
+                    $(
+                        $kind::TAG => match $kind::parse(input) {
+                            Ok((rest, msg)) => Ok((rest, $name::$kind(msg))),
+                            Err(err) => Err(err.map(|e| {
+                                ParseError::add_context(input, stringify!($kind), e)
+                            })),
+                        },
+                    )*
+                    _ => Err(nom::Err::Error(ParseError::unknown_message(input))),
+                }
+            }
+
+            /// Encode the message into the buffer, including its type byte,
+            /// returning the number of bytes written.
+            pub fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, TypeError> {
+                match self {$(
+                    $name::$kind(msg) => msg.encode_into(buf),
+                )*}
+            }
+
+            /// Encode the message, including its type byte.
+            ///
+            /// # Panics
+            /// Will panic if an option in the appendage is too long to encode,
+            /// or a character flag is not ASCII,
+            /// which `encode_into` returns as an error instead.
+            pub fn encode(&self) -> Vec<u8> {
+                match self {$(
+                    $name::$kind(msg) => msg.encode(),
+                )*}
+            }
+        }
+
+        $(
+            impl From<$kind> for $name {
+                fn from(msg: $kind) -> Self { $name::$kind(msg) }
+            }
+        )*
+    }
+}
+
+ouch_messages!{
+    /// Any message sent from the client to Nasdaq.
+    /// `LEN` for each message type excludes the options in the appendage.
+    /// ```
+    /// use nsdq_util::{ PriceOrMarket, StockSymbol };
+    /// use nsdq_util::ouch::*;
+    ///
+    /// let msg = InboundMessage::from(EnterOrder {
+    ///     user_ref_num: 1,
+    ///     side: Side::Buy,
+    ///     quantity: 100,
+    ///     symbol: StockSymbol::from("AAPL").unwrap(),
+    ///     price: PriceOrMarket::Market,
+    ///     time_in_force: TimeInForce::Day,
+    ///     display: Display::Visible,
+    ///     capacity: Capacity::Agency,
+    ///     intermarket_sweep: false,
+    ///     cross_type: CrossType::ContinuousMarket,
+    ///     cl_ord_id: ClOrdId::from("ORDER1").unwrap(),
+    ///     appendage: OptionalAppendage::new(),
+    /// });
+    ///
+    /// let bytes = msg.encode();
+    /// assert_eq!(bytes.len(), EnterOrder::LEN);
+    /// assert_eq!(msg.message_type(), b'O');
+    ///
+    /// // Reuse a preallocated send buffer instead.
+    /// let mut send_buf = [0u8; 1024];
+    /// let len = msg.encode_into(&mut send_buf).unwrap();
+    /// assert_eq!(&send_buf[..len], &bytes[..]);
+    /// assert!(msg.encode_into(&mut send_buf[..16]).is_err());
+    ///
+    /// let (rest, parsed) = InboundMessage::parse(&bytes).unwrap();
+    /// assert!(rest.is_empty());
+    /// assert_eq!(parsed, msg);
+    /// ```
+    InboundMessage;
+
+    EnterOrder,
+    ReplaceOrder,
+    CancelOrder,
+    ModifyOrder,
+    MassCancel,
+    DisableOrderEntry,
+    EnableOrderEntry,
+    AccountQuery,
+}
+
+ouch_messages!{
+    /// Any message sent from Nasdaq to the client.
+    /// `LEN` for each message type excludes the options in the appendage.
+    /// ```
+    /// use nsdq_util::NaiveTime;
+    /// use nsdq_util::ouch::*;
+    ///
+    /// let msg = OutboundMessage::from(OrderCanceled {
+    ///     timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+    ///     user_ref_num: 1,
+    ///     quantity: 100,
+    ///     reason: CancelReason::UserRequested,
+    ///     appendage: OptionalAppendage::new(),
+    /// });
+    ///
+    /// let bytes = msg.encode();
+    /// assert_eq!(bytes.len(), OrderCanceled::LEN);
+    ///
+    /// let (_, parsed) = OutboundMessage::parse(&bytes).unwrap();
+    /// assert_eq!(parsed, msg);
+    /// assert!(OutboundMessage::parse(b"O").is_err());
+    /// ```
+    OutboundMessage;
+
+    SystemEvent,
+    OrderAccepted,
+    OrderReplaced,
+    OrderCanceled,
+    AiqCanceled,
+    OrderExecuted,
+    BrokenTrade,
+    Rejected,
+    CancelPending,
+    CancelReject,
+    OrderPriorityUpdate,
+    OrderModified,
+    OrderRestated,
+    AccountQueryResponse,
+}
+
+
+/// Parse the message type byte, failing if the type does not match.
+pub(crate) fn parse_tagged(
+    tag: u8,
+    input: &[u8]
+) -> nom::IResult<&[u8], (), ParseError> {
+
+    let (input, _) = field_with(
+        "message_type",
+        Some(1),
+        |input| message_type(tag, input),
+        input
+    )?;
+
+    Ok((input, ()))
+}
+
+/// Encode a single-character field, failing if it is not ASCII.
+pub(crate) fn encode_char(name: &str, c: char) -> Result<u8, TypeError> {
+    match c.is_ascii() {
+        true => Ok(c as u8),
+        false => Err(TypeError::InvalidString(name.to_string(), c.to_string())),
+    }
+}
+
+/// Parse the message type byte, failing if it does not match.
+fn message_type(tag: u8, input: &[u8]) -> nom::IResult<&[u8], u8> {
+
+    let (rest, found) = be_u8(input)?;
+    if found != tag {
+        return Err(nom::Err::Error(
+            nom::error::Error::new(input, nom::error::ErrorKind::Tag)
+        ))
+    }
+
+    Ok((rest, found))
+}
@@ -0,0 +1,450 @@
+
+use nom::{
+    number::complete::{ be_u8, be_u16, be_u32, be_u64 },
+    bytes::complete::take,
+};
+
+use crate::{
+    define_str,
+    define_enum,
+    error::TypeError,
+    types::{ string::helper, wire::complete },
+    Mpid,
+    OuchOrderPrice,
+    PegOffset,
+    Writer,
+    parse_bool,
+    parse_ternary,
+};
+
+use super::{ Side, encode_char };
+
+
+define_str!{
+    Route [4usize]
+        "Routing strategy requested for the order, padded on the right \
+        with spaces."
+}
+
+impl Route {
+
+    /// Create a route from up to 4 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::ouch::Route;
+    ///
+    /// assert!(Route::from("SCAN").is_ok());
+    /// assert!(Route::from("SCAN1").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 4 && helper::is_alphanumeric(s) {
+            Ok(Route(helper::fixed_str::<4>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("Route"),
+                s.to_string()
+            ))
+        }
+    }
+}
+
+define_enum!{
+    CustomerType:
+        "Whether the order is a retail designated order.";
+
+    ['R'] Retail "Retail designated order",
+    ['N'] NotRetail "Not a retail designated order",
+    [' '] Default "Use the default value configured for the port",
+}
+
+define_enum!{
+    PriceType:
+        "Pricing behavior of the order.";
+
+    ['L'] Limit "Limit",
+    ['P'] MarketPeg "Market peg",
+    ['M'] MidpointPeg "Midpoint peg",
+    ['R'] PrimaryPeg "Primary peg",
+    ['m'] MidpointPegPostOnly "Midpoint peg post only",
+}
+
+define_enum!{
+    PostOnly:
+        "Whether the order should only post to the book.";
+
+    ['P'] PostOnly "Post only",
+    ['N'] No "Not post only",
+}
+
+define_enum!{
+    BboWeightIndicator:
+        "Indicates the weight of the order relative to the NBBO size.";
+
+    ['0'] Under0_2 "0 - 0.2%",
+    ['1'] From0_2To1 "0.2 - 1%",
+    ['2'] From1To2 "1 - 2%",
+    ['3'] Over2 "Greater than 2%",
+    [' '] Unspecified "Unspecified",
+}
+
+
+/// A single optional field, encoded as a TagValue option.
+/// Unrecognized tags are preserved so they can be passed through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TagValue {
+    SecondaryOrdRefNum(u64),
+    Firm(Mpid),
+    MinQty(u32),
+    CustomerType(CustomerType),
+    MaxFloor(u32),
+    PriceType(PriceType),
+    PegOffset(PegOffset),
+    DiscretionPrice(OuchOrderPrice),
+    DiscretionPegOffset(PegOffset),
+    PostOnly(PostOnly),
+    RandomReserves(u32),
+    Route(Route),
+    /// Seconds since midnight at which the order expires.
+    ExpireTime(u32),
+    /// `None` uses the default value configured for the port.
+    TradeNow(Option<bool>),
+    /// Handling instruction, as documented by Nasdaq for the order type.
+    HandleInst(char),
+    BboWeightIndicator(BboWeightIndicator),
+    DisplayQuantity(u32),
+    DisplayPrice(OuchOrderPrice),
+    GroupId(u16),
+    SharesLocated(bool),
+    LocateBroker(Mpid),
+    Side(Side),
+    UserRefIdx(u8),
+    Unknown { tag: u8, value: Vec<u8> },
+}
+
+impl TagValue {
+
+    /// Byte identifying the option.
+    pub fn tag(&self) -> u8 {
+        match self {
+            TagValue::SecondaryOrdRefNum(_) => 1,
+            TagValue::Firm(_) => 2,
+            TagValue::MinQty(_) => 3,
+            TagValue::CustomerType(_) => 4,
+            TagValue::MaxFloor(_) => 5,
+            TagValue::PriceType(_) => 6,
+            TagValue::PegOffset(_) => 7,
+            TagValue::DiscretionPrice(_) => 9,
+            TagValue::DiscretionPegOffset(_) => 11,
+            TagValue::PostOnly(_) => 12,
+            TagValue::RandomReserves(_) => 13,
+            TagValue::Route(_) => 14,
+            TagValue::ExpireTime(_) => 15,
+            TagValue::TradeNow(_) => 16,
+            TagValue::HandleInst(_) => 17,
+            TagValue::BboWeightIndicator(_) => 18,
+            TagValue::DisplayQuantity(_) => 22,
+            TagValue::DisplayPrice(_) => 23,
+            TagValue::GroupId(_) => 24,
+            TagValue::SharesLocated(_) => 25,
+            TagValue::LocateBroker(_) => 26,
+            TagValue::Side(_) => 27,
+            TagValue::UserRefIdx(_) => 28,
+            TagValue::Unknown { tag, .. } => *tag,
+        }
+    }
+
+    /// Parse a single option, starting with its 1-byte length
+    /// (which counts the tag byte and the value).
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, len) = be_u8(input)?;
+        if len == 0 {
+            return Err(nom::Err::Error(
+                nom::error::Error::new(input, nom::error::ErrorKind::LengthValue)
+            ))
+        }
+
+        let (input, option) = take(len as usize)(input)?;
+        let (value, tag) = be_u8(option)?;
+
+        let (rest, tag_value) = complete(value, Self::parse_value(tag, value))?;
+
+        // The value must fill the option exactly.
+        if !rest.is_empty() {
+            return Err(nom::Err::Error(
+                nom::error::Error::new(rest, nom::error::ErrorKind::LengthValue)
+            ))
+        }
+
+        Ok((input, tag_value))
+    }
+
+    /// Parse the value of an option, framed by its length.
+    fn parse_value(tag: u8, value: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        match tag {
+            1 => map(be_u64(value), TagValue::SecondaryOrdRefNum),
+            2 => map(Mpid::parse(value), TagValue::Firm),
+            3 => map(be_u32(value), TagValue::MinQty),
+            4 => map(CustomerType::parse(value), TagValue::CustomerType),
+            5 => map(be_u32(value), TagValue::MaxFloor),
+            6 => map(PriceType::parse(value), TagValue::PriceType),
+            7 => map(PegOffset::parse(value), TagValue::PegOffset),
+            9 => map(OuchOrderPrice::parse(value), TagValue::DiscretionPrice),
+            11 => map(PegOffset::parse(value), TagValue::DiscretionPegOffset),
+            12 => map(PostOnly::parse(value), TagValue::PostOnly),
+            13 => map(be_u32(value), TagValue::RandomReserves),
+            14 => map(Route::parse(value), TagValue::Route),
+            15 => map(be_u32(value), TagValue::ExpireTime),
+            16 => map(parse_ternary(value), TagValue::TradeNow),
+            17 => map(be_u8(value), |c| TagValue::HandleInst(c as char)),
+            18 => map(
+                BboWeightIndicator::parse(value),
+                TagValue::BboWeightIndicator
+            ),
+            22 => map(be_u32(value), TagValue::DisplayQuantity),
+            23 => map(OuchOrderPrice::parse(value), TagValue::DisplayPrice),
+            24 => map(be_u16(value), TagValue::GroupId),
+            25 => map(parse_bool(value), TagValue::SharesLocated),
+            26 => map(Mpid::parse(value), TagValue::LocateBroker),
+            27 => map(Side::parse(value), TagValue::Side),
+            28 => map(be_u8(value), TagValue::UserRefIdx),
+            _ => Ok((&[][..], TagValue::Unknown {
+                tag,
+                value: value.to_vec()
+            })),
+        }
+    }
+
+    /// Encoded length of the option, including its length and tag.
+    pub fn encoded_len(&self) -> usize {
+
+        let value = match self {
+            TagValue::SecondaryOrdRefNum(_) => 8,
+            TagValue::Firm(_) => 4,
+            TagValue::MinQty(_) => 4,
+            TagValue::CustomerType(_) => 1,
+            TagValue::MaxFloor(_) => 4,
+            TagValue::PriceType(_) => 1,
+            TagValue::PegOffset(_) => 4,
+            TagValue::DiscretionPrice(_) => 8,
+            TagValue::DiscretionPegOffset(_) => 4,
+            TagValue::PostOnly(_) => 1,
+            TagValue::RandomReserves(_) => 4,
+            TagValue::Route(_) => 4,
+            TagValue::ExpireTime(_) => 4,
+            TagValue::TradeNow(_) => 1,
+            TagValue::HandleInst(_) => 1,
+            TagValue::BboWeightIndicator(_) => 1,
+            TagValue::DisplayQuantity(_) => 4,
+            TagValue::DisplayPrice(_) => 8,
+            TagValue::GroupId(_) => 2,
+            TagValue::SharesLocated(_) => 1,
+            TagValue::LocateBroker(_) => 4,
+            TagValue::Side(_) => 1,
+            TagValue::UserRefIdx(_) => 1,
+            TagValue::Unknown { value, .. } => value.len(),
+        };
+
+        value + 2
+    }
+
+    /// Encode the option into the buffer, including its length and tag,
+    /// returning the number of bytes written.
+    /// Fails if the value is longer than the 1-byte length allows (254 bytes),
+    /// or a character value is not ASCII.
+    /// ```
+    /// use nsdq_util::ouch::TagValue;
+    ///
+    /// let mut buf = [0u8; 512];
+    /// let option = TagValue::Unknown { tag: 99, value: vec![1; 254] };
+    /// assert_eq!(option.encode_into(&mut buf), Ok(256));
+    /// assert_eq!(buf[0], 255);
+    ///
+    /// let option = TagValue::Unknown { tag: 99, value: vec![1; 255] };
+    /// assert!(option.encode_into(&mut buf).is_err());
+    ///
+    /// // Handling instructions are single ASCII characters.
+    /// assert!(TagValue::HandleInst('Y').encode_into(&mut buf).is_ok());
+    /// assert!(TagValue::HandleInst('é').encode_into(&mut buf).is_err());
+    /// ```
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let len = self.encoded_len();
+        let option_len = u8::try_from(len - 1)
+            .map_err(|_| TypeError::TooLong(len - 1, u8::MAX as usize))?;
+        if buf.len() < len {
+            return Err(TypeError::BufferTooShort(len, buf.len()))
+        }
+
+        let mut writer = Writer::new(buf);
+        writer.put(&option_len)?;
+        writer.put(&self.tag())?;
+        match self {
+            TagValue::SecondaryOrdRefNum(val) => writer.put(val)?,
+            TagValue::Firm(firm) => writer.put(firm)?,
+            TagValue::MinQty(val) => writer.put(val)?,
+            TagValue::CustomerType(val) => writer.put(val)?,
+            TagValue::MaxFloor(val) => writer.put(val)?,
+            TagValue::PriceType(val) => writer.put(val)?,
+            TagValue::PegOffset(price) => writer.put(price)?,
+            TagValue::DiscretionPrice(price) => writer.put(price)?,
+            TagValue::DiscretionPegOffset(price) => writer.put(price)?,
+            TagValue::PostOnly(val) => writer.put(val)?,
+            TagValue::RandomReserves(val) => writer.put(val)?,
+            TagValue::Route(route) => writer.put(route)?,
+            TagValue::ExpireTime(val) => writer.put(val)?,
+            TagValue::TradeNow(val) => writer.put(val)?,
+            TagValue::HandleInst(val) => {
+                writer.put(&encode_char("HandleInst", *val)?)?
+            },
+            TagValue::BboWeightIndicator(val) => writer.put(val)?,
+            TagValue::DisplayQuantity(val) => writer.put(val)?,
+            TagValue::DisplayPrice(price) => writer.put(price)?,
+            TagValue::GroupId(val) => writer.put(val)?,
+            TagValue::SharesLocated(val) => writer.put(val)?,
+            TagValue::LocateBroker(broker) => writer.put(broker)?,
+            TagValue::Side(side) => writer.put(side)?,
+            TagValue::UserRefIdx(val) => writer.put(val)?,
+            TagValue::Unknown { value, .. } => writer.put_slice(value)?,
+        }
+
+        Ok(writer.position())
+    }
+
+    /// Encode the option, including its length and tag.
+    pub fn encode(&self) -> Result<Vec<u8>, TypeError> {
+
+        let mut bytes = vec![0u8; self.encoded_len()];
+        self.encode_into(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Wrap the output of a value parser in its `TagValue` variant.
+fn map<T>(
+    parsed: nom::IResult<&[u8], T>,
+    f: impl FnOnce(T) -> TagValue
+) -> nom::IResult<&[u8], TagValue> {
+    parsed.map(|(rest, val)| (rest, f(val)))
+}
+
+
+/// Variable-length list of TagValue options trailing an OUCH message.
+/// ```
+/// use nsdq_util::{ Mpid, PegOffset };
+/// use nsdq_util::ouch::{ OptionalAppendage, TagValue };
+///
+/// let appendage = OptionalAppendage::new()
+///     .with(TagValue::Firm(Mpid::from("FIRM").unwrap()))
+///     .with(TagValue::PegOffset(PegOffset::new(-100).unwrap()))
+///     .with(TagValue::Unknown { tag: 99, value: vec![1, 2, 3] });
+///
+/// let bytes = appendage.encode().unwrap();
+/// assert_eq!(&bytes[..2], &17u16.to_be_bytes());
+/// assert_eq!(&bytes[2..8], &[5, 2, b'F', b'I', b'R', b'M']);
+///
+/// let (rest, parsed) = OptionalAppendage::parse(&bytes).unwrap();
+/// assert!(rest.is_empty());
+/// assert_eq!(parsed, appendage);
+/// assert_eq!(
+///     parsed.get(2),
+///     Some(&TagValue::Firm(Mpid::from("FIRM").unwrap()))
+/// );
+///
+/// let empty = OptionalAppendage::new();
+/// assert_eq!(empty.encode().unwrap(), vec![0, 0]);
+///
+/// // 258 options of 256 bytes each overflow the 2-byte length.
+/// let option = TagValue::Unknown { tag: 99, value: vec![1; 254] };
+/// let mut large = OptionalAppendage::new();
+/// for _ in 0..258 {
+///     large.push(option.clone());
+/// }
+/// assert!(large.encode().is_err());
+///
+/// // An option too short for its value is an error once framed.
+/// let truncated = OptionalAppendage::parse(&[0, 3, 1, 4, 0]);
+/// assert!(matches!(truncated, Err(nom::Err::Error(_))));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OptionalAppendage {
+    options: Vec<TagValue>,
+}
+
+impl OptionalAppendage {
+
+    pub fn new() -> Self { Self::default() }
+
+    /// Add an option, for chaining when building a message.
+    pub fn with(mut self, option: TagValue) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Add an option.
+    pub fn push(&mut self, option: TagValue) {
+        self.options.push(option);
+    }
+
+    /// All options, in the order they were received or added.
+    pub fn options(&self) -> &[TagValue] { &self.options }
+
+    /// First option with the given tag.
+    pub fn get(&self, tag: u8) -> Option<&TagValue> {
+        self.options.iter().find(|option| option.tag() == tag)
+    }
+
+    pub fn is_empty(&self) -> bool { self.options.is_empty() }
+
+    /// Encoded length of the options, excluding the 2-byte length prefix.
+    pub fn len(&self) -> usize {
+        self.options.iter()
+            .map(TagValue::encoded_len)
+            .sum()
+    }
+
+    /// Parse the 2-byte appendage length and the options that follow.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, len) = nom::number::streaming::be_u16(input)?;
+        let (input, mut raw) = nom::bytes::streaming::take(len as usize)(input)?;
+
+        let mut options = Vec::new();
+        while !raw.is_empty() {
+            let (rest, option) = TagValue::parse(raw)?;
+            options.push(option);
+            raw = rest;
+        }
+
+        Ok((input, Self { options }))
+    }
+
+    /// Encode the options into the buffer, prefixed by their 2-byte length,
+    /// returning the number of bytes written.
+    /// Fails if the options are longer than the 2-byte length allows.
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let len = u16::try_from(self.len())
+            .map_err(|_| TypeError::TooLong(self.len(), u16::MAX as usize))?;
+
+        let mut writer = Writer::new(buf);
+        writer.put(&len)?;
+        for option in &self.options {
+            writer.put_with(|buf| option.encode_into(buf))?;
+        }
+
+        Ok(writer.position())
+    }
+
+    /// Encode the options, prefixed by their 2-byte length.
+    pub fn encode(&self) -> Result<Vec<u8>, TypeError> {
+
+        let mut bytes = vec![0u8; self.len() + 2];
+        self.encode_into(&mut bytes)?;
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,195 @@
+
+use crate::{ define_enum, types::tick::Direction };
+
+
+define_enum!{
+    Side:
+        "Side of the order.";
+
+    ['B'] Buy "Buy",
+    ['S'] Sell "Sell",
+    ['T'] SellShort "Sell short",
+    ['E'] SellShortExempt "Sell short exempt",
+}
+
+impl Side {
+
+    /// Direction for rounding an order price to a valid tick.
+    /// ```
+    /// use nsdq_util::{ Price, ouch::codes::Side };
+    /// use nsdq_util::types::tick::{ self, Aggression, Direction };
+    ///
+    /// assert_eq!(Side::SellShort.direction(), Direction::Sell);
+    ///
+    /// let price: Price<u64, 4> = "20.0050".parse().unwrap();
+    /// let direction = Side::Buy.direction();
+    /// let bid = tick::round_order(price, direction, Aggression::Passive);
+    /// assert_eq!(bid.unwrap().to_string(), "20.0000");
+    /// ```
+    pub fn direction(&self) -> Direction {
+        match self {
+            Side::Buy => Direction::Buy,
+            Side::Sell | Side::SellShort | Side::SellShortExempt => {
+                Direction::Sell
+            },
+        }
+    }
+}
+
+define_enum!{
+    TimeInForce:
+        "Specifies how long the order will remain on the book.";
+
+    ['0'] Day "Market hours",
+    ['3'] ImmediateOrCancel "Immediate or Cancel (IOC)",
+    ['5'] GoodTillExtended "Good till extended hours (GTX)",
+    ['6'] GoodTillTriggered "Good till triggered (GTT)",
+    ['E'] AfterHours "After hours",
+}
+
+define_enum!{
+    Display:
+        "Display type of the order.";
+
+    ['Y'] Visible "Visible",
+    ['N'] Hidden "Hidden",
+    ['A'] Attributable "Attributable",
+}
+
+define_enum!{
+    Capacity:
+        "Trading capacity with which the order is entered.";
+
+    ['A'] Agency "Agency",
+    ['P'] Principal "Principal",
+    ['R'] Riskless "Riskless",
+    ['O'] Other "Other",
+}
+
+define_enum!{
+    CrossType:
+        "Cross in which the order participates.";
+
+    ['N'] ContinuousMarket "Continuous market",
+    ['O'] Opening "Opening cross",
+    ['C'] Closing "Closing cross",
+    ['H'] Halt "Halt/IPO cross",
+    ['S'] Supplemental "Supplemental order",
+    ['R'] Retail "Retail order",
+    ['E'] ExtendedLife "Extended life order",
+    ['A'] AfterHoursClose "After hours close",
+}
+
+define_enum!{
+    OrderState:
+        "Whether the order is live on the book after it is accepted.";
+
+    ['L'] Live "Order is live",
+    ['D'] Dead "Order is dead",
+}
+
+define_enum!{
+    EventCode:
+        "System event codes, signaling the start and end of the day.";
+
+    ['S'] StartOfDay
+        "Indicates that exchange is open and is ready to accept orders.",
+    ['E'] EndOfDay
+        "Indicates that exchange is closed and will not accept \
+        any new orders this day.",
+}
+
+define_enum!{
+    open CancelReason:
+        "Reason why an order was canceled.";
+
+    ['U'] UserRequested "User requested cancel",
+    ['I'] ImmediateOrCancel "Immediate or Cancel order",
+    ['T'] Timeout "Timeout; the Time In Force for this order has expired",
+    ['S'] Supervisory "Supervisory; cancelled by a Nasdaq supervisory terminal",
+    ['D'] RegulatoryRestriction
+        "Regulatory restriction; the order was cancelled to comply \
+        with a regulatory requirement",
+    ['Q'] SelfMatchPrevention "Self Match Prevention",
+    ['Z'] SystemCancel "System cancel; the order was unable to be processed",
+    ['C'] CrossCanceled "Cross cancel",
+    ['K'] MarketCollars "Market collars; the order was outside of the collar",
+    ['H'] Halted "Halted; the security was halted",
+    ['X'] OpenProtection "Open protection",
+    ['E'] Closed "Closed; the market is closed",
+    ['F'] PostOnlyCancelNms "Post only cancel (NMS)",
+    ['G'] PostOnlyCancelDisplayedPrice "Post only cancel (displayed price)",
+}
+
+define_enum!{
+    BrokenTradeReason:
+        "Reason why an execution was broken.";
+
+    ['E'] Erroneous "Erroneous; the trade was deemed clearly erroneous",
+    ['C'] Consent "Consent; the two parties mutually agreed to break the trade",
+    ['S'] Supervisory
+        "Supervisory; the trade was manually broken by a Nasdaq supervisory \
+        terminal",
+    ['X'] External
+        "External; the trade was broken by an external third party",
+}
+
+define_enum!{
+    RestateReason:
+        "Reason why an order was restated.";
+
+    ['R'] Refresh "Refresh of display (on an order with reserves)",
+    ['P'] PriceUpdate "Update of displayed price",
+}
+
+define_enum!{
+    open RejectReason
+        "Reason why an order was rejected.";
+
+    [0x0001_u16] QuoteUnavailable "Quote unavailable",
+    [0x0002_u16] DestinationClosed "Destination closed",
+    [0x0003_u16] InvalidDisplay "Invalid display",
+    [0x0004_u16] InvalidMaxFloor "Invalid max floor",
+    [0x0005_u16] InvalidPegType "Invalid peg type",
+    [0x0006_u16] FatFinger "Fat finger",
+    [0x0007_u16] Halted "Halted",
+    [0x0008_u16] IsoNotAllowed "ISO not allowed",
+    [0x0009_u16] InvalidSide "Invalid side",
+    [0x000A_u16] ProcessingError "Processing error",
+    [0x000B_u16] CancelPending "Cancel pending",
+    [0x000C_u16] FirmNotAuthorized "Firm not authorized",
+    [0x000D_u16] InvalidMinQuantity "Invalid min quantity",
+    [0x000E_u16] NoClosingReferencePrice "No closing reference price",
+    [0x000F_u16] Other "Other",
+    [0x0010_u16] CancelNotAllowed "Cancel not allowed",
+    [0x0011_u16] PeggingNotAllowed "Pegging not allowed",
+    [0x0012_u16] CrossedMarket "Crossed market",
+    [0x0013_u16] InvalidQuantity "Invalid quantity",
+    [0x0014_u16] InvalidCrossOrder "Invalid cross order",
+    [0x0015_u16] ReplaceNotAllowed "Replace not allowed",
+    [0x0016_u16] RoutingNotAllowed "Routing not allowed",
+    [0x0017_u16] InvalidSymbol "Invalid symbol",
+    [0x0018_u16] Test "Test",
+    [0x0019_u16] LateLocTooAggressive "Late LOC too aggressive",
+    [0x001A_u16] RetailNotAllowed "Retail not allowed",
+    [0x001B_u16] InvalidMidpointPostOnlyPrice
+        "Invalid midpoint post only price",
+    [0x001C_u16] InvalidDestination "Invalid destination",
+    [0x001D_u16] InvalidPrice "Invalid price",
+    [0x001E_u16] SharesExceedThreshold "Shares exceed threshold",
+    [0x001F_u16] ExceedsMaximumNotional
+        "Exceeds maximum allowed notional value",
+    [0x0020_u16] RiskAggregateExposure "Risk: Aggregate exposure exceeded",
+    [0x0021_u16] RiskMarketImpact "Risk: Market impact",
+    [0x0022_u16] RiskRestrictedStock "Risk: Restricted stock",
+    [0x0023_u16] RiskShortSellRestricted "Risk: Short sell restricted",
+    [0x0024_u16] RiskOrderTypeRestricted "Risk: Order type restricted",
+    [0x0025_u16] RiskExceedsAdvLimit "Risk: Exceeds ADV limit",
+    [0x0026_u16] RiskFatFinger "Risk: Fat finger",
+    [0x0027_u16] RiskLocateRequired "Risk: Locate required",
+    [0x0028_u16] RiskSymbolMessageRate
+        "Risk: Symbol message rate restriction",
+    [0x0029_u16] RiskPortMessageRate "Risk: Port message rate restriction",
+    [0x002A_u16] RiskDuplicateMessageRate
+        "Risk: Duplicate message rate restriction",
+}
@@ -0,0 +1,505 @@
+
+use crate::{
+    error::{ TypeError, ParseError, field, field_with },
+    Writer,
+    StockSymbol,
+    Mpid,
+    PriceOrMarket,
+};
+
+use super::{
+    ClOrdId,
+    Side,
+    TimeInForce,
+    Display,
+    Capacity,
+    CrossType,
+    parse_tagged,
+    OptionalAppendage,
+};
+
+
+/// Enter a new order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnterOrder {
+    /// Day-unique, strictly increasing identifier for the order.
+    pub user_ref_num: u32,
+    pub side: Side,
+    /// Total number of shares, including any reserve.
+    pub quantity: u32,
+    pub symbol: StockSymbol,
+    pub price: PriceOrMarket,
+    pub time_in_force: TimeInForce,
+    pub display: Display,
+    pub capacity: Capacity,
+    /// Whether the order is eligible as an Intermarket Sweep Order.
+    pub intermarket_sweep: bool,
+    pub cross_type: CrossType,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl EnterOrder {
+
+    pub const TAG: u8 = b'O';
+    pub const LEN: usize = 47;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, symbol) = field("symbol", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, time_in_force) = field("time_in_force", input)?;
+        let (input, display) = field("display", input)?;
+        let (input, capacity) = field("capacity", input)?;
+        let (input, intermarket_sweep) = field("intermarket_sweep", input)?;
+        let (input, cross_type) = field("cross_type", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            user_ref_num,
+            side,
+            quantity,
+            symbol,
+            price,
+            time_in_force,
+            display,
+            capacity,
+            intermarket_sweep,
+            cross_type,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.side)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.symbol)?;
+        writer.put(&self.price)?;
+        writer.put(&self.time_in_force)?;
+        writer.put(&self.display)?;
+        writer.put(&self.capacity)?;
+        writer.put(&self.intermarket_sweep)?;
+        writer.put(&self.cross_type)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Replace an existing order with a new order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplaceOrder {
+    /// The order being replaced.
+    pub orig_user_ref_num: u32,
+    /// The new order.
+    pub user_ref_num: u32,
+    pub quantity: u32,
+    pub price: PriceOrMarket,
+    pub time_in_force: TimeInForce,
+    pub display: Display,
+    /// Whether the order is eligible as an Intermarket Sweep Order.
+    pub intermarket_sweep: bool,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl ReplaceOrder {
+
+    pub const TAG: u8 = b'U';
+    pub const LEN: usize = 40;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, orig_user_ref_num) = field("orig_user_ref_num", input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, time_in_force) = field("time_in_force", input)?;
+        let (input, display) = field("display", input)?;
+        let (input, intermarket_sweep) = field("intermarket_sweep", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            orig_user_ref_num,
+            user_ref_num,
+            quantity,
+            price,
+            time_in_force,
+            display,
+            intermarket_sweep,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.orig_user_ref_num)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.price)?;
+        writer.put(&self.time_in_force)?;
+        writer.put(&self.display)?;
+        writer.put(&self.intermarket_sweep)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Cancel or reduce the size of an existing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelOrder {
+    pub user_ref_num: u32,
+    /// The new intended order size; 0 cancels the order in full.
+    pub quantity: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl CancelOrder {
+
+    pub const TAG: u8 = b'X';
+    pub const LEN: usize = 11;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { user_ref_num, quantity, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.quantity)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Modify the side or reduce the size of an existing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ModifyOrder {
+    pub user_ref_num: u32,
+    pub side: Side,
+    pub quantity: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl ModifyOrder {
+
+    pub const TAG: u8 = b'M';
+    pub const LEN: usize = 12;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { user_ref_num, side, quantity, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.side)?;
+        writer.put(&self.quantity)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Cancel all open orders for a firm, optionally restricted to a symbol.
+/// ```
+/// use nsdq_util::{ Mpid, StockSymbol };
+/// use nsdq_util::ouch::{ MassCancel, OptionalAppendage };
+///
+/// let msg = MassCancel {
+///     user_ref_num: 7,
+///     firm: Mpid::from("FIRM").unwrap(),
+///     symbol: StockSymbol::from("AAPL").unwrap(),
+///     appendage: OptionalAppendage::new(),
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), MassCancel::LEN);
+/// assert_eq!(&bytes[5..9], b"FIRM");
+///
+/// let (_, parsed) = MassCancel::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MassCancel {
+    pub user_ref_num: u32,
+    pub firm: Mpid,
+    /// Leave blank to cancel across all symbols.
+    pub symbol: StockSymbol,
+    pub appendage: OptionalAppendage,
+}
+
+impl MassCancel {
+
+    pub const TAG: u8 = b'C';
+    pub const LEN: usize = 19;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, firm) = field("firm", input)?;
+        let (input, symbol) = field("symbol", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { user_ref_num, firm, symbol, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.firm)?;
+        writer.put(&self.symbol)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Prevent a firm from entering new orders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisableOrderEntry {
+    pub user_ref_num: u32,
+    pub firm: Mpid,
+    pub appendage: OptionalAppendage,
+}
+
+impl DisableOrderEntry {
+
+    pub const TAG: u8 = b'D';
+    pub const LEN: usize = 11;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, firm) = field("firm", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { user_ref_num, firm, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.firm)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Allow a firm to enter new orders after order entry was disabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnableOrderEntry {
+    pub user_ref_num: u32,
+    pub firm: Mpid,
+    pub appendage: OptionalAppendage,
+}
+
+impl EnableOrderEntry {
+
+    pub const TAG: u8 = b'E';
+    pub const LEN: usize = 11;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, firm) = field("firm", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { user_ref_num, firm, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.firm)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Request the next expected UserRefNum for the account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountQuery {
+    pub appendage: OptionalAppendage,
+}
+
+impl AccountQuery {
+
+    pub const TAG: u8 = b'Q';
+    pub const LEN: usize = 3;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
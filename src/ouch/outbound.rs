@@ -0,0 +1,1028 @@
+
+use crate::{
+    error::{ TypeError, ParseError, field, field_with },
+    Writer,
+    StockSymbol,
+    OuchOrderPrice,
+    PriceOrMarket,
+    Timestamp,
+};
+
+use super::{
+    ClOrdId,
+    Side,
+    TimeInForce,
+    Display,
+    Capacity,
+    CrossType,
+    OrderState,
+    EventCode,
+    CancelReason,
+    BrokenTradeReason,
+    RestateReason,
+    RejectReason,
+    parse_tagged,
+    encode_char,
+    OptionalAppendage,
+};
+
+
+/// Signals the start or end of the trading day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemEvent {
+    pub timestamp: Timestamp,
+    pub event_code: EventCode,
+}
+
+impl SystemEvent {
+
+    pub const TAG: u8 = b'S';
+    pub const LEN: usize = 10;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, event_code) = field("event_code", input)?;
+
+        Ok((input, Self { timestamp, event_code }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.event_code)?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Acknowledges the receipt and acceptance of a valid Enter Order.
+/// ```
+/// use nsdq_util::{ NaiveTime, OuchOrderPrice, PriceOrMarket, StockSymbol };
+/// use nsdq_util::ouch::*;
+///
+/// let msg = OrderAccepted {
+///     timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+///     user_ref_num: 1,
+///     side: Side::SellShort,
+///     quantity: 100,
+///     symbol: StockSymbol::from("AAPL").unwrap(),
+///     price: OuchOrderPrice::new(150_0000).unwrap().into(),
+///     time_in_force: TimeInForce::ImmediateOrCancel,
+///     display: Display::Hidden,
+///     order_reference_number: 123456789,
+///     capacity: Capacity::Principal,
+///     intermarket_sweep: true,
+///     cross_type: CrossType::ContinuousMarket,
+///     order_state: OrderState::Live,
+///     cl_ord_id: ClOrdId::from("ORDER1").unwrap(),
+///     appendage: OptionalAppendage::new(),
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), OrderAccepted::LEN);
+///
+/// let (_, parsed) = OrderAccepted::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);
+///
+/// // Market orders are echoed with the sentinel price.
+/// let msg = OrderAccepted { price: PriceOrMarket::Market, ..msg };
+/// let (_, parsed) = OrderAccepted::parse(&msg.encode()).unwrap();
+/// assert_eq!(parsed.price, PriceOrMarket::Market);
+/// assert_eq!(parsed.price.limit(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderAccepted {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub side: Side,
+    pub quantity: u32,
+    pub symbol: StockSymbol,
+    pub price: PriceOrMarket,
+    pub time_in_force: TimeInForce,
+    pub display: Display,
+    /// Day-unique identifier assigned by Nasdaq, as seen on ITCH.
+    pub order_reference_number: u64,
+    pub capacity: Capacity,
+    /// Whether the order is eligible as an Intermarket Sweep Order.
+    pub intermarket_sweep: bool,
+    pub cross_type: CrossType,
+    pub order_state: OrderState,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderAccepted {
+
+    pub const TAG: u8 = b'A';
+    pub const LEN: usize = 64;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, symbol) = field("symbol", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, time_in_force) = field("time_in_force", input)?;
+        let (input, display) = field("display", input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, capacity) = field("capacity", input)?;
+        let (input, intermarket_sweep) = field("intermarket_sweep", input)?;
+        let (input, cross_type) = field("cross_type", input)?;
+        let (input, order_state) = field("order_state", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            side,
+            quantity,
+            symbol,
+            price,
+            time_in_force,
+            display,
+            order_reference_number,
+            capacity,
+            intermarket_sweep,
+            cross_type,
+            order_state,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.side)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.symbol)?;
+        writer.put(&self.price)?;
+        writer.put(&self.time_in_force)?;
+        writer.put(&self.display)?;
+        writer.put(&self.order_reference_number)?;
+        writer.put(&self.capacity)?;
+        writer.put(&self.intermarket_sweep)?;
+        writer.put(&self.cross_type)?;
+        writer.put(&self.order_state)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Acknowledges the replacement of an existing order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderReplaced {
+    pub timestamp: Timestamp,
+    /// The order that was replaced.
+    pub orig_user_ref_num: u32,
+    /// The new order.
+    pub user_ref_num: u32,
+    pub side: Side,
+    pub quantity: u32,
+    pub symbol: StockSymbol,
+    pub price: PriceOrMarket,
+    pub time_in_force: TimeInForce,
+    pub display: Display,
+    /// Day-unique identifier assigned by Nasdaq, as seen on ITCH.
+    pub order_reference_number: u64,
+    pub capacity: Capacity,
+    /// Whether the order is eligible as an Intermarket Sweep Order.
+    pub intermarket_sweep: bool,
+    pub cross_type: CrossType,
+    pub order_state: OrderState,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderReplaced {
+
+    pub const TAG: u8 = b'U';
+    pub const LEN: usize = 68;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, orig_user_ref_num) = field("orig_user_ref_num", input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, symbol) = field("symbol", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, time_in_force) = field("time_in_force", input)?;
+        let (input, display) = field("display", input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, capacity) = field("capacity", input)?;
+        let (input, intermarket_sweep) = field("intermarket_sweep", input)?;
+        let (input, cross_type) = field("cross_type", input)?;
+        let (input, order_state) = field("order_state", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            orig_user_ref_num,
+            user_ref_num,
+            side,
+            quantity,
+            symbol,
+            price,
+            time_in_force,
+            display,
+            order_reference_number,
+            capacity,
+            intermarket_sweep,
+            cross_type,
+            order_state,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.orig_user_ref_num)?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.side)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.symbol)?;
+        writer.put(&self.price)?;
+        writer.put(&self.time_in_force)?;
+        writer.put(&self.display)?;
+        writer.put(&self.order_reference_number)?;
+        writer.put(&self.capacity)?;
+        writer.put(&self.intermarket_sweep)?;
+        writer.put(&self.cross_type)?;
+        writer.put(&self.order_state)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An order has been reduced or canceled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderCanceled {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    /// Number of shares decremented from the order.
+    pub quantity: u32,
+    pub reason: CancelReason,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderCanceled {
+
+    pub const TAG: u8 = b'C';
+    pub const LEN: usize = 20;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, reason) = field("reason", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            quantity,
+            reason,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.reason)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An order was reduced or canceled by Anti-Internalization (AIQ).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AiqCanceled {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    /// Number of shares decremented from the order.
+    pub decrement_shares: u32,
+    pub reason: CancelReason,
+    /// Number of shares that would have executed without AIQ.
+    pub quantity_prevented: u32,
+    /// Price at which the shares would have executed.
+    pub execution_price: OuchOrderPrice,
+    /// Liquidity flag that would have applied to the execution.
+    pub liquidity_flag: char,
+    /// Self-match prevention strategy that was applied.
+    pub aiq_strategy: char,
+    pub appendage: OptionalAppendage,
+}
+
+impl AiqCanceled {
+
+    pub const TAG: u8 = b'D';
+    pub const LEN: usize = 34;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, decrement_shares) = field("decrement_shares", input)?;
+        let (input, reason) = field("reason", input)?;
+        let (input, quantity_prevented) = field("quantity_prevented", input)?;
+        let (input, execution_price) = field("execution_price", input)?;
+        let (input, liquidity_flag) = field::<u8>("liquidity_flag", input)?;
+        let (input, aiq_strategy) = field::<u8>("aiq_strategy", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            decrement_shares,
+            reason,
+            quantity_prevented,
+            execution_price,
+            liquidity_flag: liquidity_flag as char,
+            aiq_strategy: aiq_strategy as char,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.decrement_shares)?;
+        writer.put(&self.reason)?;
+        writer.put(&self.quantity_prevented)?;
+        writer.put(&self.execution_price)?;
+        writer.put(&encode_char("liquidity_flag", self.liquidity_flag)?)?;
+        writer.put(&encode_char("aiq_strategy", self.aiq_strategy)?)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An order has been executed in whole or in part.
+/// ```
+/// use nsdq_util::{ NaiveTime, OuchOrderPrice };
+/// use nsdq_util::ouch::{ OrderExecuted, OptionalAppendage };
+///
+/// let msg = OrderExecuted {
+///     timestamp: NaiveTime::from_hms_opt(9, 30, 0).unwrap().into(),
+///     user_ref_num: 1,
+///     quantity: 100,
+///     price: OuchOrderPrice::new(150_0000).unwrap(),
+///     liquidity_flag: 'A',
+///     match_number: 99,
+///     appendage: OptionalAppendage::new(),
+/// };
+///
+/// let bytes = msg.encode();
+/// assert_eq!(bytes.len(), OrderExecuted::LEN);
+/// assert_eq!(bytes[25], b'A');
+///
+/// let (_, parsed) = OrderExecuted::parse(&bytes).unwrap();
+/// assert_eq!(parsed, msg);///
+/// // Flags are single ASCII characters.
+/// let mut buf = [0u8; 64];
+/// let msg = OrderExecuted { liquidity_flag: 'é', ..msg };
+/// assert!(msg.encode_into(&mut buf).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderExecuted {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    /// Number of shares executed.
+    pub quantity: u32,
+    pub price: OuchOrderPrice,
+    /// Liquidity flag, as documented by Nasdaq for billing.
+    pub liquidity_flag: char,
+    /// Day-unique match number, as seen on ITCH.
+    pub match_number: u64,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderExecuted {
+
+    pub const TAG: u8 = b'E';
+    pub const LEN: usize = 36;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, liquidity_flag) = field::<u8>("liquidity_flag", input)?;
+        let (input, match_number) = field("match_number", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            quantity,
+            price,
+            liquidity_flag: liquidity_flag as char,
+            match_number,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.quantity)?;
+        writer.put(&self.price)?;
+        writer.put(&encode_char("liquidity_flag", self.liquidity_flag)?)?;
+        writer.put(&self.match_number)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An execution has been broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BrokenTrade {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    /// Match number of the execution being broken.
+    pub match_number: u64,
+    pub reason: BrokenTradeReason,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl BrokenTrade {
+
+    pub const TAG: u8 = b'B';
+    pub const LEN: usize = 38;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, match_number) = field("match_number", input)?;
+        let (input, reason) = field("reason", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            match_number,
+            reason,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.match_number)?;
+        writer.put(&self.reason)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An Enter Order was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rejected {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub reason: RejectReason,
+    pub cl_ord_id: ClOrdId,
+    pub appendage: OptionalAppendage,
+}
+
+impl Rejected {
+
+    pub const TAG: u8 = b'J';
+    pub const LEN: usize = 31;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, reason) = field("reason", input)?;
+        let (input, cl_ord_id) = field("cl_ord_id", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            reason,
+            cl_ord_id,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.reason)?;
+        writer.put(&self.cl_ord_id)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// A cancel request was received for an order in a cross,
+/// and will be processed after the cross.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelPending {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl CancelPending {
+
+    pub const TAG: u8 = b'P';
+    pub const LEN: usize = 15;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { timestamp, user_ref_num, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// A cancel request was rejected because the order is in a cross.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CancelReject {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl CancelReject {
+
+    pub const TAG: u8 = b'I';
+    pub const LEN: usize = 15;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { timestamp, user_ref_num, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An order's price or display has changed, giving it a new priority.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderPriorityUpdate {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub price: PriceOrMarket,
+    pub display: Display,
+    /// New day-unique identifier assigned by Nasdaq, as seen on ITCH.
+    pub order_reference_number: u64,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderPriorityUpdate {
+
+    pub const TAG: u8 = b'T';
+    pub const LEN: usize = 32;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, price) = field("price", input)?;
+        let (input, display) = field("display", input)?;
+        let (input, order_reference_number) =
+            field("order_reference_number", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            price,
+            display,
+            order_reference_number,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.price)?;
+        writer.put(&self.display)?;
+        writer.put(&self.order_reference_number)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Acknowledges a Modify Order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderModified {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub side: Side,
+    /// Number of shares remaining on the order.
+    pub quantity: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderModified {
+
+    pub const TAG: u8 = b'M';
+    pub const LEN: usize = 20;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, side) = field("side", input)?;
+        let (input, quantity) = field("quantity", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self {
+            timestamp,
+            user_ref_num,
+            side,
+            quantity,
+            appendage,
+        }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.side)?;
+        writer.put(&self.quantity)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// An order's display or price was changed by Nasdaq.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderRestated {
+    pub timestamp: Timestamp,
+    pub user_ref_num: u32,
+    pub reason: RestateReason,
+    pub appendage: OptionalAppendage,
+}
+
+impl OrderRestated {
+
+    pub const TAG: u8 = b'R';
+    pub const LEN: usize = 16;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, user_ref_num) = field("user_ref_num", input)?;
+        let (input, reason) = field("reason", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { timestamp, user_ref_num, reason, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.user_ref_num)?;
+        writer.put(&self.reason)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
+
+
+/// Response to an Account Query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AccountQueryResponse {
+    pub timestamp: Timestamp,
+    /// The next UserRefNum expected by Nasdaq for the account.
+    pub next_user_ref_num: u32,
+    pub appendage: OptionalAppendage,
+}
+
+impl AccountQueryResponse {
+
+    pub const TAG: u8 = b'Q';
+    pub const LEN: usize = 15;
+
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self, ParseError> {
+
+        let (input, _) = parse_tagged(Self::TAG, input)?;
+        let (input, timestamp) =
+            field_with("timestamp", Some(8), Timestamp::parse_ouch, input)?;
+        let (input, next_user_ref_num) = field("next_user_ref_num", input)?;
+        let (input, appendage) = field_with(
+            "appendage",
+            None,
+            OptionalAppendage::parse,
+            input
+        )?;
+
+        Ok((input, Self { timestamp, next_user_ref_num, appendage }))
+    }
+
+    pub fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+
+        let mut writer = Writer::new(buf);
+        writer.put(&Self::TAG)?;
+        writer.put_slice(&self.timestamp.encode_ouch())?;
+        writer.put(&self.next_user_ref_num)?;
+        writer.put_with(|buf| self.appendage.encode_into(buf))?;
+        Ok(writer.position())
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+
+        let mut bytes = vec![0u8; Self::LEN + self.appendage.len()];
+        self.encode_into(&mut bytes)
+            .expect("Buffer is sized for the message, with encodable fields");
+        bytes
+    }
+}
@@ -0,0 +1,399 @@
+
+use nom::{
+    number::streaming::be_u16,
+    bytes::streaming::take,
+    error::ContextError,
+};
+
+use crate::{
+    define_str,
+    define_enum,
+    error::{ TypeError, ParseError },
+    types::{ string::helper, wire::complete },
+};
+
+
+define_str!{
+    Username [6usize]
+        "SoupBinTCP username, padded on the right with spaces."
+}
+
+impl Username {
+
+    /// Create a username from up to 6 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::soupbin::Username;
+    ///
+    /// assert!(Username::from("USER01").is_ok());
+    /// assert!(Username::from("USER-1").is_err());
+    /// assert!(Username::from("USERNAME").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 6 && helper::is_alphanumeric(s) {
+            Ok(Username(helper::fixed_str::<6>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("Username"),
+                s.to_string()
+            ))
+        }
+    }
+}
+
+define_str!{
+    Password [10usize]
+        "SoupBinTCP password, padded on the right with spaces."
+}
+
+impl Password {
+
+    /// Create a password from up to 10 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::soupbin::Password;
+    ///
+    /// assert!(Password::from("hunter2").is_ok());
+    /// assert!(Password::from("hunter2!").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 10 && helper::is_alphanumeric(s) {
+            Ok(Password(helper::fixed_str::<10>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("Password"),
+                s.to_string()
+            ))
+        }
+    }
+}
+
+define_str!{
+    Session [10usize]
+        "SoupBinTCP session name, padded on the left with spaces. \
+        An all-blank session requests the currently active session."
+}
+
+impl Default for Session {
+    fn default() -> Self { Session(*b"          ") }
+}
+
+impl Session {
+
+    /// Create a session name from up to 10 alphanumeric characters.
+    /// ```
+    /// use nsdq_util::soupbin::Session;
+    ///
+    /// let session = Session::from("ABC123").unwrap();
+    /// assert_eq!(&session.encode(), b"    ABC123");
+    /// assert_eq!(session.name(), "ABC123");
+    /// assert!(Session::from("ABC_123").is_err());
+    /// ```
+    pub fn from(s: impl AsRef<str>) -> Result<Self, TypeError> {
+
+        let s = s.as_ref();
+        if s.len() <= 10 && helper::is_alphanumeric(s) {
+            Ok(Session(helper::fixed_str_left::<10>(s)))
+        } else {
+            Err(TypeError::InvalidString(
+                String::from("Session"),
+                s.to_string()
+            ))
+        }
+    }
+
+    /// Session name without the left padding.
+    pub fn name(&self) -> &str {
+        self.to_str().trim_start()
+    }
+}
+
+
+define_enum!{
+    RejectReason:
+        "Reason given by the server for rejecting a login request.";
+
+    ['A'] NotAuthorized
+        "Not Authorized. There was an invalid username and password \
+        combination in the Login Request Message.",
+    ['S'] SessionNotAvailable
+        "Session not available. The Requested Session in the Login Request \
+        Packet was either invalid or not available.",
+}
+
+
+/// Sent by the client to open a session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginRequest {
+    pub username: Username,
+    pub password: Password,
+    /// Leave blank to log into the currently active session.
+    pub session: Session,
+    /// Next sequence number the client expects, or 0 to start
+    /// receiving only new messages.
+    pub sequence_number: u64,
+}
+
+/// Sent by the server when a login request is accepted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginAccepted {
+    /// The session that has been joined.
+    pub session: Session,
+    /// Sequence number of the next Sequenced Data packet to be sent.
+    pub sequence_number: u64,
+}
+
+/// All SoupBinTCP packet types, both client- and server-originated.
+/// Every packet is prefixed by a 2-byte big-endian length
+/// (which counts the packet type byte but not itself).
+/// ```
+/// use nsdq_util::soupbin::{ Packet, LoginAccepted, Session };
+///
+/// let packet = Packet::LoginAccepted(LoginAccepted {
+///     session: Session::from("SESSION1").unwrap(),
+///     sequence_number: 42,
+/// });
+///
+/// let bytes = packet.encode().unwrap();
+/// assert_eq!(bytes.len(), 33);
+/// assert_eq!(&bytes[..3], &[0, 31, b'A']);
+///
+/// let (rest, parsed) = Packet::parse(&bytes).unwrap();
+/// assert_eq!(parsed, packet);
+/// assert!(rest.is_empty());
+///
+/// // A partial read asks for more data.
+/// assert!(matches!(Packet::parse(&bytes[..10]), Err(nom::Err::Incomplete(_))));
+///
+/// // A complete packet with a short payload is an error.
+/// assert!(matches!(Packet::parse(&[0, 1, b'J']), Err(nom::Err::Error(_))));
+///
+/// // The length prefix counts the packet type byte.
+/// assert!(Packet::SequencedData(vec![0; 65534]).encode().is_ok());
+/// assert!(Packet::SequencedData(vec![0; 65535]).encode().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    /// Free-form human-readable text, sent by either party.
+    Debug(Vec<u8>),
+    LoginAccepted(LoginAccepted),
+    LoginRejected(RejectReason),
+    /// Carries a higher-level protocol message (e.g. OUCH outbound).
+    SequencedData(Vec<u8>),
+    ServerHeartbeat,
+    EndOfSession,
+    LoginRequest(LoginRequest),
+    /// Carries a higher-level protocol message (e.g. OUCH inbound).
+    UnsequencedData(Vec<u8>),
+    ClientHeartbeat,
+    LogoutRequest,
+}
+
+impl Packet {
+
+    /// Byte identifying the packet type.
+    pub fn packet_type(&self) -> u8 {
+        match self {
+            Packet::Debug(_) => b'+',
+            Packet::LoginAccepted(_) => b'A',
+            Packet::LoginRejected(_) => b'J',
+            Packet::SequencedData(_) => b'S',
+            Packet::ServerHeartbeat => b'H',
+            Packet::EndOfSession => b'Z',
+            Packet::LoginRequest(_) => b'L',
+            Packet::UnsequencedData(_) => b'U',
+            Packet::ClientHeartbeat => b'R',
+            Packet::LogoutRequest => b'O',
+        }
+    }
+
+    /// Parse a single length-prefixed packet.
+    /// Returns `nom::Err::Incomplete` if the full packet has not arrived yet.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, len) = be_u16(input)?;
+        if len == 0 {
+            return Err(nom::Err::Error(
+                nom::error::Error::new(input, nom::error::ErrorKind::LengthValue)
+            ))
+        }
+
+        let (input, body) = take(len as usize)(input)?;
+        let (_, packet) = complete(body, Self::parse_body(body))?;
+
+        Ok((input, packet))
+    }
+
+    /// Parse the packet type and payload, framed by the length prefix.
+    fn parse_body(body: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (ptype, payload) = body.split_at(1);
+
+        let packet = match ptype[0] {
+            b'+' => Packet::Debug(payload.to_vec()),
+            b'A' => {
+                let (rest, session) = Session::parse(payload)?;
+                let (_, sequence_number) = parse_numeric(rest)?;
+                Packet::LoginAccepted(LoginAccepted { session, sequence_number })
+            },
+            b'J' => {
+                let (_, reason) = RejectReason::parse(payload)?;
+                Packet::LoginRejected(reason)
+            },
+            b'S' => Packet::SequencedData(payload.to_vec()),
+            b'H' => Packet::ServerHeartbeat,
+            b'Z' => Packet::EndOfSession,
+            b'L' => {
+                let (rest, username) = Username::parse(payload)?;
+                let (rest, password) = Password::parse(rest)?;
+                let (rest, session) = Session::parse(rest)?;
+                let (_, sequence_number) = parse_numeric(rest)?;
+                Packet::LoginRequest(LoginRequest {
+                    username,
+                    password,
+                    session,
+                    sequence_number
+                })
+            },
+            b'U' => Packet::UnsequencedData(payload.to_vec()),
+            b'R' => Packet::ClientHeartbeat,
+            b'O' => Packet::LogoutRequest,
+            _ => return Err(nom::Err::Error(
+                nom::error::Error::new(body, nom::error::ErrorKind::Tag)
+            )),
+        };
+
+        Ok((&[], packet))
+    }
+
+    /// Encode the packet, including its length prefix.
+    /// Fails if the packet is too long for the 2-byte length,
+    /// which counts the packet type byte.
+    pub fn encode(&self) -> Result<Vec<u8>, TypeError> {
+
+        let mut payload = vec![self.packet_type()];
+        match self {
+            Packet::Debug(data) |
+            Packet::SequencedData(data) |
+            Packet::UnsequencedData(data) => payload.extend(data),
+            Packet::LoginAccepted(login) => {
+                payload.extend(login.session.encode());
+                payload.extend(encode_numeric(login.sequence_number));
+            },
+            Packet::LoginRejected(reason) => payload.extend(reason.encode()),
+            Packet::LoginRequest(login) => {
+                payload.extend(login.username.encode());
+                payload.extend(login.password.encode());
+                payload.extend(login.session.encode());
+                payload.extend(encode_numeric(login.sequence_number));
+            },
+            Packet::ServerHeartbeat |
+            Packet::EndOfSession |
+            Packet::ClientHeartbeat |
+            Packet::LogoutRequest => {},
+        }
+
+        let len = u16::try_from(payload.len())
+            .map_err(|_| TypeError::TooLong(payload.len(), u16::MAX as usize))?;
+
+        let mut bytes = Vec::with_capacity(payload.len() + 2);
+        bytes.extend(len.to_be_bytes());
+        bytes.extend(payload);
+        Ok(bytes)
+    }
+}
+
+
+/// Buffers partial reads from a stream and yields complete packets.
+/// ```
+/// use nsdq_util::soupbin::{ Decoder, Packet };
+///
+/// let mut bytes = Packet::ServerHeartbeat.encode().unwrap();
+/// bytes.extend(Packet::SequencedData(b"data".to_vec()).encode().unwrap());
+///
+/// let mut decoder = Decoder::new();
+/// decoder.feed(&bytes[..4]);
+/// assert_eq!(decoder.decode().unwrap(), Some(Packet::ServerHeartbeat));
+/// assert_eq!(decoder.decode().unwrap(), None);
+///
+/// decoder.feed(&bytes[4..]);
+/// assert_eq!(
+///     decoder.decode().unwrap(),
+///     Some(Packet::SequencedData(b"data".to_vec()))
+/// );
+/// assert_eq!(decoder.decode().unwrap(), None);
+///
+/// // A malformed packet is skipped rather than waited on.
+/// decoder.feed(&[0, 1, b'J']);
+/// decoder.feed(&Packet::ServerHeartbeat.encode().unwrap());
+/// assert!(decoder.decode().is_err());
+/// assert_eq!(decoder.decode().unwrap(), Some(Packet::ServerHeartbeat));
+/// assert_eq!(decoder.buffered(), 0);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Decoder {
+    buf: Vec<u8>,
+}
+
+impl Decoder {
+
+    pub fn new() -> Self { Self::default() }
+
+    /// Append bytes read from the stream.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Number of buffered bytes not yet decoded.
+    pub fn buffered(&self) -> usize { self.buf.len() }
+
+    /// Decode the next complete packet, if one is buffered.
+    /// A malformed packet is discarded so that decoding can continue.
+    pub fn decode(
+        &mut self
+    ) -> Result<Option<Packet>, ParseError> {
+
+        match Packet::parse(&self.buf) {
+            Ok((rest, packet)) => {
+                let used = self.buf.len() - rest.len();
+                self.buf.drain(..used);
+                Ok(Some(packet))
+            },
+            Err(nom::Err::Incomplete(_)) => Ok(None),
+            Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => {
+                let e = ParseError::add_context(
+                    &self.buf,
+                    "Packet",
+                    ParseError::from(e)
+                );
+                let len = u16::from_be_bytes([self.buf[0], self.buf[1]]);
+                self.buf.drain(..(2 + len as usize));
+                Err(e)
+            },
+        }
+    }
+}
+
+
+/// Parse a 20-byte numeric field, padded on the left with spaces.
+fn parse_numeric(input: &[u8]) -> nom::IResult<&[u8], u64> {
+
+    let (input, raw) = nom::bytes::complete::take(20usize)(input)?;
+    let val = std::str::from_utf8(raw).ok()
+        .map(|s| s.trim())
+        .and_then(|s| if s.is_empty() { Some(0) } else { s.parse().ok() });
+
+    match val {
+        Some(val) => Ok((input, val)),
+        None => Err(nom::Err::Error(
+            nom::error::Error::new(raw, nom::error::ErrorKind::Digit)
+        )),
+    }
+}
+
+/// Encode a 20-byte numeric field, padded on the left with spaces.
+fn encode_numeric(val: u64) -> [u8; 20] {
+    helper::fixed_str_left::<20>(&val.to_string())
+}
@@ -1,7 +1,13 @@
 
 pub mod enums;
 pub mod price;
+pub mod dyn_price;
+pub mod notional;
+pub mod domain;
+pub mod tick;
 pub mod string;
 pub mod time;
 pub mod bools;
+pub mod wire;
+pub mod serialize;
 
@@ -0,0 +1,216 @@
+
+use nom::error::{ Error, ErrorKind };
+
+use crate::error::TypeError;
+use super::{
+    price::{ Price, parse_decimal },
+    wire::Wire,
+};
+
+/// Define a price field with its own valid range,
+/// validated on construction and when parsed.
+macro_rules! define_price_domain {
+    (
+        $name:ident: Price<$int:ty, $n:literal>, $min:expr, $max:expr;
+        $doc:expr;
+    ) => {
+
+        #[doc = $doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(Price<$int, $n>);
+
+        impl $name {
+
+            /// Lowest valid value, with the decimal implied.
+            #[allow(clippy::inconsistent_digit_grouping)]
+            pub const MIN: $int = $min;
+            /// Highest valid value, with the decimal implied.
+            #[allow(clippy::inconsistent_digit_grouping)]
+            pub const MAX: $int = $max;
+
+            pub fn new(val: $int) -> Result<Self, TypeError> {
+                match (Self::MIN..=Self::MAX).contains(&val) {
+                    true => Ok(Self(Price::from_raw(val))),
+                    false => Err(TypeError::InvalidPrice(format!(
+                        "{} ({})", val, stringify!($name)
+                    ))),
+                }
+            }
+
+            /// Copy the price with dollars and cents together.
+            pub fn val(&self) -> $int { self.0.val() }
+
+            /// The price, without its domain.
+            pub fn price(&self) -> Price<$int, $n> { self.0 }
+
+            /// Encode price as big-endian bytes.
+            pub fn encode(&self) -> [u8; std::mem::size_of::<$int>()] {
+                self.val().to_be_bytes()
+            }
+
+            /// Parse price, failing if it is out of range.
+            pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                let (rest, price) = <Price<$int, $n> as Wire>::parse(input)?;
+                match Self::new(price.val()) {
+                    Ok(price) => Ok((rest, price)),
+                    Err(_) => Err(nom::Err::Error(
+                        Error::new(input, ErrorKind::Verify)
+                    )),
+                }
+            }
+        }
+
+        impl TryFrom<Price<$int, $n>> for $name {
+            type Error = TypeError;
+            fn try_from(price: Price<$int, $n>) -> Result<Self, TypeError> {
+                Self::new(price.val())
+            }
+        }
+
+        impl From<$name> for Price<$int, $n> {
+            fn from(price: $name) -> Self { price.0 }
+        }
+
+        impl Wire for $name {
+
+            const LEN: usize = <Price<$int, $n> as Wire>::LEN;
+
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+
+            fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+                self.0.encode_into(buf)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = TypeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let invalid = || TypeError::InvalidPrice(String::from(s));
+                let (val, decimals) = parse_decimal(s)
+                    .filter(|(_, decimals)| *decimals <= $n)
+                    .ok_or_else(invalid)?;
+                let val = val.checked_mul(10i128.pow(($n - decimals) as u32))
+                    .and_then(|val| <$int>::try_from(val).ok())
+                    .ok_or_else(invalid)?;
+                Self::new(val).map_err(|_| invalid())
+            }
+        }
+    };
+}
+
+
+define_price_domain!{
+    OuchOrderPrice: Price<u64, 4>, 0, 199_999_9900;
+    "Limit price for OUCH order entry, up to $199,999.9900. \
+    Market orders use a sentinel instead, see `PriceOrMarket`.";
+}
+
+define_price_domain!{
+    MwcbLevel: Price<u64, 8>, 0, 999_999_9999_9999;
+    "Market-wide circuit breaker decline level, Price(8) in ITCH, \
+    which has at most 6 whole number places.";
+}
+
+define_price_domain!{
+    PegOffset: Price<i32, 4>, -199_999_9900, 199_999_9900;
+    "Signed offset from the peg price for OUCH order entry.";
+}
+
+
+/// OUCH order price, which is either a limit price
+/// or one of the sentinels that flag a market order.
+///```
+/// use nsdq_util::{ OuchOrderPrice, PriceOrMarket };
+///
+/// let (_, price) = PriceOrMarket::parse(&150_0000u64.to_be_bytes()).unwrap();
+/// assert_eq!(price, PriceOrMarket::Limit(OuchOrderPrice::new(150_0000).unwrap()));
+///
+/// let (_, price) = PriceOrMarket::parse(&200_000_0000u64.to_be_bytes()).unwrap();
+/// assert_eq!(price, PriceOrMarket::Market);
+///
+/// let (_, price) = PriceOrMarket::parse(&214_748_3647u64.to_be_bytes()).unwrap();
+/// assert_eq!(price, PriceOrMarket::MarketCross);
+/// assert_eq!(price.encode(), 214_748_3647u64.to_be_bytes());
+///
+/// // Neither a valid limit price nor a sentinel.
+/// assert!(PriceOrMarket::parse(&200_000_0001u64.to_be_bytes()).is_err());
+/// assert!(OuchOrderPrice::new(200_000_0000).is_err());
+///
+/// assert_eq!("1.25".parse::<OuchOrderPrice>().unwrap().val(), 1_2500);
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PriceOrMarket {
+    Limit(OuchOrderPrice),
+    /// $200,000.0000, flagging a market order.
+    Market,
+    /// $214,748.3647, flagging a market order in a cross.
+    MarketCross,
+}
+
+impl PriceOrMarket {
+
+    /// Sentinel for a market order.
+    #[allow(clippy::inconsistent_digit_grouping)]
+    pub const MARKET: u64 = 200_000_0000;
+    /// Sentinel for a market order in a cross.
+    #[allow(clippy::inconsistent_digit_grouping)]
+    pub const MARKET_CROSS: u64 = 214_748_3647;
+
+    /// Limit price, or `None` for a market order.
+    pub fn limit(&self) -> Option<OuchOrderPrice> {
+        match self {
+            PriceOrMarket::Limit(price) => Some(*price),
+            _ => None,
+        }
+    }
+
+    /// Price as sent on the wire, including the sentinels.
+    pub fn price(&self) -> Price<u64, 4> {
+        match self {
+            PriceOrMarket::Limit(price) => price.price(),
+            PriceOrMarket::Market => Price::from_raw(Self::MARKET),
+            PriceOrMarket::MarketCross => Price::from_raw(Self::MARKET_CROSS),
+        }
+    }
+
+    /// Encode price as big-endian bytes.
+    pub fn encode(&self) -> [u8; 8] { self.price().encode() }
+
+    /// Parse price from 8 bytes.
+    pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (rest, price) = Price::<u64, 4>::parse(input)?;
+        let price = match price.val() {
+            Self::MARKET => PriceOrMarket::Market,
+            Self::MARKET_CROSS => PriceOrMarket::MarketCross,
+            val => match OuchOrderPrice::new(val) {
+                Ok(price) => PriceOrMarket::Limit(price),
+                Err(_) => return Err(nom::Err::Error(
+                    Error::new(input, ErrorKind::Verify)
+                )),
+            },
+        };
+        Ok((rest, price))
+    }
+}
+
+impl From<OuchOrderPrice> for PriceOrMarket {
+    fn from(price: OuchOrderPrice) -> Self { PriceOrMarket::Limit(price) }
+}
+
+impl Wire for PriceOrMarket {
+    const LEN: usize = 8;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Self::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        self.price().encode_into(buf)
+    }
+}
@@ -0,0 +1,230 @@
+
+use std::{ cmp::Ordering, hash::{ Hash, Hasher } };
+
+use nom::error::{ Error, ErrorKind };
+
+use crate::error::TypeError;
+use super::price::{ Price, Rounding, fmt_decimal, parse_decimal };
+
+/// Price with a precision known only at runtime,
+/// for feeds whose implied decimal places vary by message,
+/// or which carry whole dollars.
+/// Prices compare by value, regardless of precision.
+///```
+/// use nsdq_util::{ DynPrice, Price };
+///
+/// let (_, price) = DynPrice::parse_u32(&35000u32.to_be_bytes(), 4).unwrap();
+/// assert_eq!(price.to_string(), "3.5000");
+/// assert_eq!(price.parts(), (3, 5000));
+///
+/// let (_, index) = DynPrice::parse_u64(&1_234_567u64.to_be_bytes(), 2).unwrap();
+/// assert_eq!(index.to_string(), "12345.67");
+///
+/// let (_, strike) = DynPrice::parse_u16(&150u16.to_be_bytes(), 0).unwrap();
+/// assert_eq!(strike.to_string(), "150");
+///
+/// assert_eq!("3.5".parse::<DynPrice>().unwrap(), price);
+/// assert_eq!(format!("{:#}", price), "3.5");
+/// assert!("3.5".parse::<DynPrice>().unwrap() < "3.5001".parse().unwrap());
+///
+/// let fixed = Price::<u32, 4>::new(35000).unwrap();
+/// assert_eq!(DynPrice::from(fixed), price);
+/// assert_eq!(Price::<u64, 8>::try_from(price).unwrap().val(), 3_5000_0000);
+/// assert!(Price::<u32, 4>::try_from(DynPrice::new(35, 5).unwrap()).is_err());
+///```
+#[derive(Debug, Clone, Copy)]
+pub struct DynPrice {
+    val: i128,
+    precision: u8,
+}
+
+impl DynPrice {
+
+    /// Highest supported number of decimal places.
+    pub const MAX_PRECISION: u8 = 18;
+
+    /// Fails if the precision is above `MAX_PRECISION`,
+    /// or the value does not fit in 64 bits.
+    pub fn new(val: i128, precision: u8) -> Result<Self, TypeError> {
+
+        let too_large = val.unsigned_abs() > u64::MAX as u128;
+        if precision > Self::MAX_PRECISION || too_large {
+            return Err(TypeError::InvalidPrice(format!("{}e-{}", val, precision)))
+        }
+        Ok(Self { val, precision })
+    }
+
+    /// Copy the price with dollars and cents together.
+    pub fn val(&self) -> i128 { self.val }
+
+    /// Number of implied decimal places.
+    pub fn precision(&self) -> u8 { self.precision }
+
+    /// Returns whole dollars, remainder (cents)
+    pub fn parts(&self) -> (i128, u128) {
+        let denom = 10_i128.pow(self.precision as u32);
+        (self.val / denom, (self.val % denom).unsigned_abs())
+    }
+
+    /// Parse price from 2 bytes.
+    pub fn parse_u16(input: &[u8], precision: u8) -> nom::IResult<&[u8], Self> {
+        let (rest, val) = nom::number::streaming::be_u16(input)?;
+        Self::verify(input, rest, val as i128, precision)
+    }
+
+    /// Parse price from 4 bytes.
+    pub fn parse_u32(input: &[u8], precision: u8) -> nom::IResult<&[u8], Self> {
+        let (rest, val) = nom::number::streaming::be_u32(input)?;
+        Self::verify(input, rest, val as i128, precision)
+    }
+
+    /// Parse signed price from 4 bytes.
+    pub fn parse_i32(input: &[u8], precision: u8) -> nom::IResult<&[u8], Self> {
+        let (rest, val) = nom::number::streaming::be_i32(input)?;
+        Self::verify(input, rest, val as i128, precision)
+    }
+
+    /// Parse price from 8 bytes.
+    pub fn parse_u64(input: &[u8], precision: u8) -> nom::IResult<&[u8], Self> {
+        let (rest, val) = nom::number::streaming::be_u64(input)?;
+        Self::verify(input, rest, val as i128, precision)
+    }
+
+    fn verify<'a>(
+        input: &'a [u8],
+        rest: &'a [u8],
+        val: i128,
+        precision: u8
+    ) -> nom::IResult<&'a [u8], Self> {
+
+        match Self::new(val, precision) {
+            Ok(price) => Ok((rest, price)),
+            Err(_) => Err(nom::Err::Error(Error::new(input, ErrorKind::Verify))),
+        }
+    }
+
+    /// Convert to another precision, rounding if it is reduced.
+    /// Fails if the precision is above `MAX_PRECISION`,
+    /// or the value no longer fits in 64 bits.
+    ///```
+    /// use nsdq_util::{ DynPrice, Rounding };
+    ///
+    /// let price: DynPrice = "1.005".parse().unwrap();
+    /// assert_eq!(price.rescale(2, Rounding::Up).unwrap().to_string(), "1.01");
+    /// assert_eq!(price.rescale(6, Rounding::Up).unwrap().to_string(), "1.005000");
+    /// assert!(price.rescale(40, Rounding::Up).is_err());
+    ///```
+    pub fn rescale(
+        &self,
+        precision: u8,
+        rounding: Rounding
+    ) -> Result<Self, TypeError> {
+
+        let invalid = || TypeError::InvalidPrice(self.to_string());
+        if precision > Self::MAX_PRECISION { return Err(invalid()) }
+        let val = match precision.cmp(&self.precision) {
+            Ordering::Less => {
+                let denom = 10i128.pow((self.precision - precision) as u32);
+                rounding.div(self.val, denom)
+            },
+            _ => {
+                let scale = 10i128.pow((precision - self.precision) as u32);
+                self.val.checked_mul(scale).ok_or_else(invalid)?
+            },
+        };
+        Self::new(val, precision).map_err(|_| invalid())
+    }
+
+    /// Smallest precision representing the same value.
+    fn normalize(&self) -> (i128, u8) {
+        let (mut val, mut precision) = (self.val, self.precision);
+        while precision > 0 && val % 10 == 0 {
+            val /= 10;
+            precision -= 1;
+        }
+        (val, precision)
+    }
+}
+
+impl PartialEq for DynPrice {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for DynPrice {}
+
+impl PartialOrd for DynPrice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Values fit in 64 bits and precision is at most 18,
+/// so rescaling both to the larger precision fits in an `i128`.
+impl Ord for DynPrice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let precision = self.precision.max(other.precision);
+        let scale = |p: &DynPrice| {
+            p.val * 10i128.pow((precision - p.precision) as u32)
+        };
+        scale(self).cmp(&scale(other))
+    }
+}
+
+impl Hash for DynPrice {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.normalize().hash(state) }
+}
+
+impl std::fmt::Display for DynPrice {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_decimal(f, self.val, self.precision)
+    }
+}
+
+/// The precision is the number of decimal places given.
+impl std::str::FromStr for DynPrice {
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TypeError::InvalidPrice(String::from(s));
+        let (val, precision) = parse_decimal(s).ok_or_else(invalid)?;
+        Self::new(val, precision).map_err(|_| invalid())
+    }
+}
+
+/// Lossless, for the precisions supported by `DynPrice`.
+macro_rules! dyn_price_from {
+    ($( $int:ty ),*) => { $(
+        impl<const N: u8> From<Price<$int, N>> for DynPrice {
+            fn from(price: Price<$int, N>) -> Self {
+                const { assert!(N <= DynPrice::MAX_PRECISION) };
+                DynPrice { val: price.val() as i128, precision: N }
+            }
+        }
+    )* };
+}
+
+dyn_price_from!(u32, i32, u64);
+
+/// Exact conversion, failing if digits would be lost
+/// or the price is out of range for the result.
+impl<I, const N: u8> TryFrom<DynPrice> for Price<I, N>
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    type Error = TypeError;
+
+    fn try_from(price: DynPrice) -> Result<Self, TypeError> {
+
+        let invalid = || TypeError::InvalidPrice(price.to_string());
+        if N > DynPrice::MAX_PRECISION {
+            return Err(invalid())
+        }
+
+        let scaled = price.rescale(N, Rounding::Down)?;
+        if scaled != price {
+            return Err(invalid())
+        }
+        let val = I::from(scaled.val).ok_or_else(invalid)?;
+        Price::new(val).map_err(|_| invalid())
+    }
+}
@@ -27,6 +27,8 @@
 ///
 /// assert_eq!(&bytes, b"AB");
 ///
+/// assert_eq!(MyEnum::VariantA.description(), "This is the first variant for MyEnum.");
+/// assert_eq!(MyEnum::VariantB.description(), "VariantB");
 /// ```
 ///
 /// For multi-alpha-character enum variants:
@@ -91,8 +93,372 @@
 /// assert_eq!(MyEnum::Var1.encode(), bytes1);
 /// assert_eq!(MyEnum::Var2.encode(), bytes2);
 /// ```
+///
+/// Prefixing any form with `open` adds an `Unknown` variant holding the
+/// raw code, so that codes added to the protocol later do not fail parsing.
+/// `parse_strict` still rejects them, and `encode` writes them back as received.
+/// Every form returns `nom::Err::Incomplete` for a truncated code.
+/// ```
+/// use nsdq_util::define_enum;
+///
+/// define_enum!{
+///     open MyEnum "Enum with u16 tag, keeping unknown codes.";
+///
+///     [0x0001_u16] Var1 "Variant with docs",
+/// }
+///
+/// let bytes = 7u16.to_be_bytes();
+/// let (_, unknown) = MyEnum::parse(&bytes).unwrap();
+///
+/// assert_eq!(unknown, MyEnum::Unknown(7));
+/// assert!(unknown.is_unknown());
+/// assert_eq!(unknown.encode(), bytes);
+/// match MyEnum::parse_strict(&bytes) {
+///     Err(nom::Err::Error(e)) => assert_eq!(e.input, &bytes),
+///     _ => panic!("Code 7 is unknown"),
+/// }
+/// assert!(matches!(MyEnum::parse(&[0]), Err(nom::Err::Incomplete(_))));
+/// assert_eq!(MyEnum::parse_strict(&1u16.to_be_bytes()).unwrap().1, MyEnum::Var1);
+///
+/// define_enum!{
+///     open MyEnum2 [2usize] "Enum with tag length of 2 bytes.";
+///
+///     [b"AB"] Var1,
+/// }
+///
+/// let (_, unknown) = MyEnum2::parse(b"CD").unwrap();
+/// assert_eq!(unknown, MyEnum2::Unknown(*b"CD"));
+/// assert_eq!(&unknown.encode(), b"CD");
+/// assert_eq!(unknown.description(), "Unknown code");
+///
+/// // Constructing `Unknown` with a known code is not canonical.
+/// let known = MyEnum2::Unknown(*b"AB");
+/// assert_eq!(known, MyEnum2::Var1);
+/// assert!(known.is_unknown());
+/// assert!(!known.normalize().is_unknown());
+/// ```
+///
+/// With the `serde` feature, each form also implements `Serialize`
+/// and `Deserialize` by variant name, also accepting the wire code
+/// (a one-character string, a `u16`, or the byte string respectively)
+/// when deserializing. The `Unknown` variant of an `open` enum
+/// is serialized as its wire code.
+/// Accepting both forms needs a self-describing format such as JSON.
 #[macro_export] macro_rules! define_enum {
 
+    // char tags, keeping unknown codes
+    (open $name:ident: $edoc:expr;
+        $([$tag:expr] $kind:ident $($kdoc:expr)?),*$(,)?
+    ) => {
+
+        #[doc = $edoc]
+        #[derive(Debug, Clone, Copy, Eq)]
+        pub enum $name {
+            $(
+                $(#[doc = $kdoc])?
+                $kind,
+            )*
+            /// Code not known to this version of the crate, as received.
+            /// `parse` never produces it for a known code,
+            /// and it compares equal to the known variant if constructed so.
+            Unknown(u8),
+        }
+
+        impl $name {
+
+            /// Parse the code, keeping unrecognized codes as `Unknown`.
+            pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                use nom::bytes::streaming::take;
+
+                let (rest, ch) = take(1usize)(input)?;
+                let kind = match ch[0] as char {
+                    $(
+                        $tag => Self::$kind,
+                    )*
+                    _ => Self::Unknown(ch[0]),
+                };
+
+                Ok((rest, kind))
+            }
+
+            /// Parse the code, failing if it is not recognized.
+            pub fn parse_strict(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                match Self::parse(input)? {
+                    (_, Self::Unknown(_)) => Err(nom::Err::Error(
+                        nom::error::Error::new(
+                            input,
+                            nom::error::ErrorKind::Tag
+                        )
+                    )),
+                    parsed => Ok(parsed),
+                }
+            }
+
+            /// Whether the code is not known to this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+
+            /// Known variant for the code, if the code is known.
+            pub fn normalize(self) -> Self {
+                Self::parse(&self.encode()).map_or(self, |(_, kind)| kind)
+            }
+
+            #[allow(clippy::char_lit_as_u8)]
+            pub fn encode(&self) -> [u8; 1] {
+                match self {
+                    $(
+                        $name::$kind => [$tag as u8],
+                    )*
+                    $name::Unknown(raw) => [*raw],
+                }
+            }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                    )*
+                    $name::Unknown(_) => "Unknown code",
+                }
+            }
+        }
+
+        $crate::__serde_enum!(char $name; $([$tag] $kind),*; Unknown);
+
+        /// Compares by wire code, so `Unknown` equals a known code's variant.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.encode() == other.encode()
+            }
+        }
+
+        impl $crate::Wire for $name {
+            const LEN: usize = 1;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
+        }
+    };
+
+    // u16-tags, keeping unknown codes
+    (open $name:ident $edoc:expr;
+        $([$tag:expr] $kind:ident $($kdoc:expr)?),*$(,)?
+    ) => {
+
+        #[doc = $edoc]
+        #[derive(Debug, Clone, Copy, Eq)]
+        pub enum $name {
+            $(
+                $(#[doc = $kdoc])?
+                $kind,
+            )*
+            /// Code not known to this version of the crate, as received.
+            /// `parse` never produces it for a known code,
+            /// and it compares equal to the known variant if constructed so.
+            Unknown(u16),
+        }
+
+        impl $name {
+
+            /// Parse the code, keeping unrecognized codes as `Unknown`.
+            pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                let (rest, tag) = nom::number::streaming::be_u16(input)?;
+                let kind = match tag {
+                    $(
+                        $tag => Self::$kind,
+                    )*
+                    _ => Self::Unknown(tag),
+                };
+
+                Ok((rest, kind))
+            }
+
+            /// Parse the code, failing if it is not recognized.
+            pub fn parse_strict(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                match Self::parse(input)? {
+                    (_, Self::Unknown(_)) => Err(nom::Err::Error(
+                        nom::error::Error::new(
+                            input,
+                            nom::error::ErrorKind::Tag
+                        )
+                    )),
+                    parsed => Ok(parsed),
+                }
+            }
+
+            /// Whether the code is not known to this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+
+            /// Known variant for the code, if the code is known.
+            pub fn normalize(self) -> Self {
+                Self::parse(&self.encode()).map_or(self, |(_, kind)| kind)
+            }
+
+            pub fn encode(&self) -> [u8; 2] {
+                match self {
+                    $(
+                        $name::$kind => $tag.to_be_bytes(),
+                    )*
+                    $name::Unknown(raw) => raw.to_be_bytes(),
+                }
+            }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                    )*
+                    $name::Unknown(_) => "Unknown code",
+                }
+            }
+        }
+
+        $crate::__serde_enum!(u16 $name; $([$tag] $kind),*; Unknown);
+
+        /// Compares by wire code, so `Unknown` equals a known code's variant.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.encode() == other.encode()
+            }
+        }
+
+        impl $crate::Wire for $name {
+            const LEN: usize = 2;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
+        }
+    };
+
+    // fixed-length byte array tags, keeping unknown codes
+    (open $name:ident [$len:expr] $edoc:expr;
+        $([$tag:expr] $kind:ident $($kdoc:expr)?),*$(,)?
+    ) => {
+
+        #[doc = $edoc]
+        #[derive(Debug, Clone, Copy, Eq)]
+        pub enum $name {
+            $(
+                $(#[doc = $kdoc])?
+                $kind,
+            )*
+            /// Code not known to this version of the crate, as received.
+            /// `parse` never produces it for a known code,
+            /// and it compares equal to the known variant if constructed so.
+            Unknown([u8; $len]),
+        }
+
+        impl $name {
+
+            /// Parse the code, keeping unrecognized codes as `Unknown`.
+            pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                use nom::bytes::streaming::take;
+
+                let (rest, chars) = take($len)(input)?;
+                let kind = match chars {
+                    $(
+                        $tag => Self::$kind,
+                    )*
+                    _ => Self::Unknown(
+                        chars.try_into().expect(&format!("Take {} bytes", $len))
+                    ),
+                };
+
+                Ok((rest, kind))
+            }
+
+            /// Parse the code, failing if it is not recognized.
+            pub fn parse_strict(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+                match Self::parse(input)? {
+                    (_, Self::Unknown(_)) => Err(nom::Err::Error(
+                        nom::error::Error::new(
+                            input,
+                            nom::error::ErrorKind::Tag
+                        )
+                    )),
+                    parsed => Ok(parsed),
+                }
+            }
+
+            /// Whether the code is not known to this version of the crate.
+            pub fn is_unknown(&self) -> bool {
+                matches!(self, Self::Unknown(_))
+            }
+
+            /// Known variant for the code, if the code is known.
+            pub fn normalize(self) -> Self {
+                Self::parse(&self.encode()).map_or(self, |(_, kind)| kind)
+            }
+
+            pub fn encode(&self) -> [u8; $len] {
+                match self {
+                    $(
+                        $name::$kind => *$tag,
+                    )*
+                    $name::Unknown(raw) => *raw,
+                }
+            }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(
+                        $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                    )*
+                    $name::Unknown(_) => "Unknown code",
+                }
+            }
+        }
+
+        $crate::__serde_enum!(bytes $name; $([$tag] $kind),*; Unknown);
+
+        /// Compares by wire code, so `Unknown` equals a known code's variant.
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.encode() == other.encode()
+            }
+        }
+
+        impl $crate::Wire for $name {
+            const LEN: usize = $len;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
+        }
+    };
+
     // char tags
     ($name:ident: $edoc:expr; 
         $([$tag:expr] $kind:ident $($kdoc:expr)?),*$(,)?
@@ -108,29 +474,53 @@
         impl $name {
             pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
 
-                use nom::bytes::complete::take;
+                use nom::bytes::streaming::take;
 
-                let (input, ch) = take(1usize)(input)?;
+                let (rest, ch) = take(1usize)(input)?;
                 let kind = match ch[0] as char {
                     $(
                         $tag => Self::$kind,
                     )*
                     _ => return Err(nom::Err::Error(
                         nom::error::Error::new(
-                            input, 
+                            input,
                             nom::error::ErrorKind::Tag
                         )
                     )),
                 };
 
-                Ok((input, kind))
+                Ok((rest, kind))
             }
 
+            #[allow(clippy::char_lit_as_u8)]
             pub fn encode(&self) -> [u8; 1] {
                 match self {$(
                     $name::$kind => [$tag as u8],
                 )*}
             }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {$(
+                    $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                )*}
+            }
+        }
+
+        $crate::__serde_enum!(char $name; $([$tag] $kind),*);
+
+        impl $crate::Wire for $name {
+            const LEN: usize = 1;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
         }
     };
 
@@ -149,20 +539,20 @@
         impl $name {
             pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
 
-                let (input, tag) = nom::number::streaming::be_u16(input)?;
+                let (rest, tag) = nom::number::streaming::be_u16(input)?;
                 let kind = match tag {
                     $(
                         $tag => Self::$kind,
                     )*
                     _ => return Err(nom::Err::Error(
                         nom::error::Error::new(
-                            input, 
+                            input,
                             nom::error::ErrorKind::Tag
                         )
                     )),
                 };
 
-                Ok((input, kind))
+                Ok((rest, kind))
             }
 
             pub fn encode(&self) -> [u8; 2] {
@@ -170,6 +560,29 @@
                     $name::$kind => $tag.to_be_bytes(),
                 )*}
             }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {$(
+                    $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                )*}
+            }
+        }
+
+        $crate::__serde_enum!(u16 $name; $([$tag] $kind),*);
+
+        impl $crate::Wire for $name {
+            const LEN: usize = 2;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
         }
     };
 
@@ -188,22 +601,22 @@
         impl $name {
             pub fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
 
-                use nom::bytes::complete::take;
+                use nom::bytes::streaming::take;
 
-                let (input, chars) = take($len)(input)?;
+                let (rest, chars) = take($len)(input)?;
                 let kind = match chars {
                     $(
                         $tag => Self::$kind,
                     )*
                     _ => return Err(nom::Err::Error(
                         nom::error::Error::new(
-                            input, 
+                            input,
                             nom::error::ErrorKind::Tag
                         )
                     )),
                 };
 
-                Ok((input, kind))
+                Ok((rest, kind))
             }
 
             pub fn encode(&self) -> [u8; $len] {
@@ -211,6 +624,29 @@
                     $name::$kind => *$tag,
                 )*}
             }
+
+            /// Meaning of the code as documented in the specification,
+            /// or the variant name if it is undocumented.
+            pub fn description(&self) -> &'static str {
+                match self {$(
+                    $name::$kind => [$($kdoc,)? stringify!($kind)][0],
+                )*}
+            }
+        }
+
+        $crate::__serde_enum!(bytes $name; $([$tag] $kind),*);
+
+        impl $crate::Wire for $name {
+            const LEN: usize = $len;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.encode())
+            }
         }
     };
 
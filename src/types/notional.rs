@@ -0,0 +1,119 @@
+
+use std::ops::{ Add, AddAssign, Neg, Sub, SubAssign };
+
+use crate::error::TypeError;
+use super::price::{ fmt_decimal, parse_decimal };
+
+/// Exact value of a quantity of shares at a price,
+/// with the same implied decimal places `N` as the price.
+/// Signed, so that it can also hold P&L.
+///```
+/// use nsdq_util::{ Notional, OuchOrderPrice, Price };
+///
+/// let max = OuchOrderPrice::new(OuchOrderPrice::MAX).unwrap().price();
+/// let order = max.notional(u32::MAX);
+/// assert_eq!(order.to_string(), "858993416050327.0500");
+///
+/// let buy = Price::<u32, 4>::new(150_0000).unwrap().notional(100);
+/// let sell = Price::<u32, 4>::new(151_2500).unwrap().notional(100);
+/// assert_eq!((sell - buy).to_string(), "125.0000");
+/// assert_eq!(format!("{:#}", buy - sell), "-125");
+/// assert!(sell > buy);
+///
+/// let total: Notional<4> = [buy, sell].into_iter().sum();
+/// assert_eq!(total.val(), 301_2500 * 100);
+///```
+///
+/// More than 38 decimal places fails to compile.
+///```compile_fail
+/// let notional = nsdq_util::Notional::<39>::new(1);
+///```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Notional<const N: u8> {
+    val: i128,
+}
+
+impl<const N: u8> Notional<N> {
+
+    /// One dollar in units of `N` decimal places,
+    /// failing to compile for more places than an `i128` can hold.
+    const DOLLAR: i128 = {
+        assert!(N <= 38, "Notional supports at most 38 decimal places");
+        10_i128.pow(N as u32)
+    };
+
+    /// Value with `N` implied decimal places.
+    pub fn new(val: i128) -> Self {
+        let _ = Self::DOLLAR;
+        Self { val }
+    }
+
+    /// Copy the value with dollars and cents together.
+    pub fn val(&self) -> i128 { self.val }
+
+    /// Returns whole dollars, remainder (cents)
+    pub fn parts(&self) -> (i128, u128) {
+        let denom = Self::DOLLAR;
+        (self.val / denom, (self.val % denom).unsigned_abs())
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.val.checked_add(rhs.val).map(Self::new)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.val.checked_sub(rhs.val).map(Self::new)
+    }
+}
+
+impl<const N: u8> Add for Notional<N> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self { Self::new(self.val + rhs.val) }
+}
+
+impl<const N: u8> Sub for Notional<N> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self { Self::new(self.val - rhs.val) }
+}
+
+impl<const N: u8> Neg for Notional<N> {
+    type Output = Self;
+    fn neg(self) -> Self { Self::new(-self.val) }
+}
+
+impl<const N: u8> AddAssign for Notional<N> {
+    fn add_assign(&mut self, rhs: Self) { self.val += rhs.val }
+}
+
+impl<const N: u8> SubAssign for Notional<N> {
+    fn sub_assign(&mut self, rhs: Self) { self.val -= rhs.val }
+}
+
+impl<const N: u8> std::iter::Sum for Notional<N> {
+    fn sum<T: Iterator<Item = Self>>(iter: T) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+/// Same notation as `Price`, including `{:#}` to trim trailing zeros.
+impl<const N: u8> std::fmt::Display for Notional<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let _ = Self::DOLLAR;
+        fmt_decimal(f, self.val, N)
+    }
+}
+
+/// Accepts up to `N` decimal places.
+impl<const N: u8> std::str::FromStr for Notional<N> {
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || TypeError::InvalidPrice(String::from(s));
+        let (val, decimals) = parse_decimal(s)
+            .filter(|(_, decimals)| *decimals <= N)
+            .ok_or_else(invalid)?;
+        val.checked_mul(Self::DOLLAR / 10_i128.pow(decimals as u32))
+            .map(Self::new)
+            .ok_or_else(invalid)
+    }
+}
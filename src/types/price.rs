@@ -1,11 +1,15 @@
 
 use crate::error::TypeError;
+use super::{
+    notional::Notional,
+    wire::{ Wire, write_bytes },
+};
 
 /// Prices are integer fields, supplied with an associated precision. 
 /// When converted to a decimal format, prices are in fixed point format, 
 /// where `N` defines the number of decimal places. 
 /// For example, Price<I, 4> has an implied 4 decimal places. 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Price<I, const N: u8> {
     /// Dollar and cents together, with the decimal marked by `precision`.
     val: I,
@@ -13,10 +17,9 @@ pub struct Price<I, const N: u8> {
 
 impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
 
-    /// The maximum price one would need to create is $199,999.9900,
-    /// which is the maximum order price for OUCH.
-    /// NOTE: This function will always fail for N < 2 
-    /// (cannot create whole dollars without a fractional component).
+    /// Fails if the precision `N` is too large for the integer type.
+    /// Protocol fields with their own ranges, such as OUCH order prices,
+    /// are validated by the types in `types::domain`.
     ///```
     /// use nsdq_util::Price;
     ///
@@ -27,24 +30,620 @@ impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
     ///```
     pub fn new(val: I) -> Result<Self, TypeError> {
 
-        if N < 2 {
-            return Err(TypeError::InvalidPrice(String::from("N")))
+        let ten = I::from(10).expect("Price integers hold 10");
+        match num_traits::checked_pow(ten, N as usize) {
+            Some(_) => Ok(Self { val }),
+            None => Err(TypeError::InvalidPrice(String::from("N"))),
         }
+    }
+
+    /// Copy the price with dollars and cents together.
+    pub fn val(&self) -> I { self.val }
+
+    /// Wrap a value already validated by the caller,
+    /// such as a price domain with its own range.
+    pub(crate) fn from_raw(val: I) -> Self { Self { val } }
+}
+
+
+/// Arithmetic preserves the precision `N`.
+/// Checked operations return `None` if the result would overflow,
+/// and the operators panic in that case, like integer overflow.
+///```
+/// use nsdq_util::{ Price, Rounding };
+///
+/// let bid = Price::<u32, 4>::new(150_0000).unwrap();
+/// let tick = Price::<u32, 4>::new(100).unwrap();
+///
+/// assert_eq!(bid + tick, Price::new(150_0100).unwrap());
+/// assert_eq!(bid - tick, Price::new(149_9900).unwrap());
+/// assert_eq!(bid * 2, Price::new(300_0000).unwrap());
+/// assert_eq!(bid / 3, Price::new(50_0000).unwrap());
+/// assert!(bid > tick);
+///
+/// assert_eq!(tick.checked_sub(bid), None);
+/// assert_eq!(tick.saturating_sub(bid), Price::new(0).unwrap());
+/// assert_eq!(bid.checked_mul(100_000), None);
+/// assert_eq!(bid.checked_div(0), None);
+///
+/// let max = Price::<u32, 4>::new(u32::MAX).unwrap();
+/// assert_eq!(max.checked_add(tick), None);
+/// assert_eq!(max.saturating_add(tick), max);
+///
+/// let ask = Price::<u32, 4>::new(150_0001).unwrap();
+/// assert_eq!(bid.midpoint(ask, Rounding::Down).val(), 150_0000);
+/// assert_eq!(bid.midpoint(ask, Rounding::Up).val(), 150_0001);
+/// assert_eq!(bid.midpoint(ask, Rounding::HalfEven).val(), 150_0000);
+///```
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::new(self.val.checked_add(&rhs.val)?).ok()
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::new(self.val.checked_sub(&rhs.val)?).ok()
+    }
+
+    /// Multiply by a scalar.
+    pub fn checked_mul(self, rhs: I) -> Option<Self> {
+        Self::new(self.val.checked_mul(&rhs)?).ok()
+    }
+
+    /// Divide by a scalar, truncating toward zero.
+    pub fn checked_div(self, rhs: I) -> Option<Self> {
+        Self::new(self.val.checked_div(&rhs)?).ok()
+    }
+
+    /// Add, clamping to the range of the integer type.
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self { val: self.val.saturating_add(rhs.val) }
+    }
+
+    /// Subtract, clamping to the range of the integer type.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self { val: self.val.saturating_sub(rhs.val) }
+    }
+
+    /// Value of `shares` at this price,
+    /// which cannot overflow for integer types of up to 64 bits.
+    ///
+    /// # Panics
+    /// Will panic if a 128-bit price times `shares` is out of range.
+    pub fn notional(self, shares: u32) -> Notional<N> {
+        let val = to_i128(self.val).checked_mul(shares.into());
+        Notional::new(val.expect("Notional is in range"))
+    }
+
+    /// Price halfway between two prices,
+    /// rounded to the precision `N` when it falls between increments.
+    pub fn midpoint(self, other: Self, rounding: Rounding) -> Self {
+
+        let sum = to_i128(self.val) + to_i128(other.val);
+        let mid = rounding.div(sum, 2);
+        Self { val: I::from(mid).expect("Midpoint is between the prices") }
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::ops::Add
+    for Price<I, N>
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Will panic if the result is out of range.
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("Price sum is in range")
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::ops::Sub
+    for Price<I, N>
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Will panic if the result is out of range.
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("Price difference is in range")
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::ops::Mul<I>
+    for Price<I, N>
+{
+    type Output = Self;
+
+    /// # Panics
+    /// Will panic if the result is out of range.
+    fn mul(self, rhs: I) -> Self {
+        self.checked_mul(rhs).expect("Price product is in range")
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::ops::Div<I>
+    for Price<I, N>
+{
+    type Output = Self;
+
+    /// Truncates toward zero.
+    ///
+    /// # Panics
+    /// Will panic if dividing by zero.
+    fn div(self, rhs: I) -> Self {
+        self.checked_div(rhs).expect("Divisor is not zero")
+    }
+}
 
-        // Should always be in range
-        // Subtract 2 to account for $0.99
-        let mag = I::from(10).unwrap().pow((N as u32) - 2);
-        let limit = I::from(199_999_99).unwrap() * mag;
 
-        if val <= limit {
-            Ok(Self { val })
-        } else {
-            Err(TypeError::InvalidPrice(format!("{}", val)))
+/// Conversion between precisions and integer widths.
+/// Conversions that can never lose information are `From`,
+/// other exact conversions are `TryFrom`, and `rescale` rounds explicitly.
+///```
+/// use nsdq_util::{ Price, Rounding };
+///
+/// // An ITCH quote price submitted as an OUCH order price.
+/// let quote = Price::<u32, 4>::new(150_0100).unwrap();
+/// let order: Price<u64, 4> = quote.into();
+/// assert_eq!(order.val(), 150_0100);
+///
+/// let level: Price<u64, 8> = quote.into();
+/// assert_eq!(level.val(), 150_0100_0000);
+///
+/// let offset = Price::<i32, 4>::try_from(quote).unwrap();
+/// assert_eq!(offset.val(), 150_0100);
+/// assert!(Price::<u32, 4>::try_from(Price::<i32, 4>::new(-1).unwrap()).is_err());
+///
+/// // Reducing precision is exact, unless rounded.
+/// let level = Price::<u64, 8>::new(150_0100_5000).unwrap();
+/// assert!(Price::<u32, 4>::try_from(level).is_err());
+/// assert_eq!(level.rescale::<u32, 4>(Rounding::Down).unwrap().val(), 150_0100);
+/// assert_eq!(level.rescale::<u32, 4>(Rounding::Up).unwrap().val(), 150_0101);
+/// assert_eq!(level.rescale::<u32, 4>(Rounding::HalfEven).unwrap().val(), 150_0100);
+///
+/// let cents: Price<u32, 2> = quote.rescale(Rounding::Up).unwrap();
+/// assert_eq!(cents.to_string(), "150.01");
+/// let cents: Price<u32, 2> = level.rescale(Rounding::Up).unwrap();
+/// assert_eq!(cents.to_string(), "150.02");
+///
+/// // Precisions beyond the range of the integer type fail.
+/// assert!(quote.rescale::<u64, 50>(Rounding::Down).is_err());
+///```
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
+
+    /// Convert to another precision and width, failing if
+    /// any digits would be lost or the price is out of range for the result.
+    pub fn try_rescale<J, const M: u8>(self) -> Result<Price<J, M>, TypeError>
+    where
+        J: num_traits::PrimInt + std::fmt::Display,
+    {
+        let invalid = || TypeError::InvalidPrice(self.to_string());
+
+        let val = to_i128(self.val);
+        if M < N && val % pow10(N - M).ok_or_else(invalid)? != 0 {
+            return Err(invalid())
         }
+        self.rescale(Rounding::Down)
     }
 
-    /// Copy the price with dollars and cents together.
-    pub fn val(&self) -> I { self.val }
+    /// Convert to another precision and width,
+    /// rounding if the precision is reduced.
+    /// Fails if the price is out of range for the result.
+    pub fn rescale<J, const M: u8>(
+        self,
+        rounding: Rounding
+    ) -> Result<Price<J, M>, TypeError>
+    where
+        J: num_traits::PrimInt + std::fmt::Display,
+    {
+        let invalid = || TypeError::InvalidPrice(self.to_string());
+
+        let val = to_i128(self.val);
+        let val = match M.cmp(&N) {
+            std::cmp::Ordering::Less => {
+                rounding.div(val, pow10(N - M).ok_or_else(invalid)?)
+            },
+            _ => pow10(M - N).and_then(|scale| val.checked_mul(scale))
+                .ok_or_else(invalid)?,
+        };
+
+        Price::new(J::from(val).ok_or_else(invalid)?).map_err(|_| invalid())
+    }
+}
+
+impl<const N: u8> From<Price<u32, N>> for Price<u64, N> {
+    fn from(price: Price<u32, N>) -> Self {
+        Price { val: price.val as u64 }
+    }
+}
+
+impl From<Price<u32, 4>> for Price<u64, 8> {
+    fn from(price: Price<u32, 4>) -> Self {
+        Price { val: price.val as u64 * 10_000 }
+    }
+}
+
+/// Exact conversions that may fail, through `try_rescale`.
+macro_rules! price_try_from {
+    ($( ($from:ty, $n:literal) => ($to:ty, $m:literal) ),* $(,)?) => { $(
+        impl TryFrom<Price<$from, $n>> for Price<$to, $m> {
+            type Error = TypeError;
+            fn try_from(price: Price<$from, $n>) -> Result<Self, TypeError> {
+                price.try_rescale()
+            }
+        }
+    )* };
+
+    ($( $from:ty => $to:ty ),* $(,)?) => { $(
+        impl<const N: u8> TryFrom<Price<$from, N>> for Price<$to, N> {
+            type Error = TypeError;
+            fn try_from(price: Price<$from, N>) -> Result<Self, TypeError> {
+                price.try_rescale()
+            }
+        }
+    )* };
+}
+
+price_try_from!(
+    u64 => u32,
+    u32 => i32,
+    i32 => u32,
+    u64 => i32,
+    i32 => u64,
+);
+
+price_try_from!(
+    (u64, 4) => (u64, 8),
+    (i32, 4) => (u64, 8),
+    (u64, 8) => (u64, 4),
+    (u64, 8) => (u32, 4),
+    (u64, 8) => (i32, 4),
+);
+
+
+/// Conversion from `f64`, through its shortest decimal representation,
+/// so that `3.5001` converts to exactly $3.5001.
+/// Digits beyond the precision `N` are rounded explicitly,
+/// and `TryFrom<f64>` rounds to the nearest increment.
+///```
+/// use nsdq_util::{ Price, Rounding };
+///
+/// let price = Price::<u32, 4>::try_from(3.5001).unwrap();
+/// assert_eq!(price.val(), 3_5001);
+/// assert_eq!(price.to_f64(), 3.5001);
+///
+/// let price = Price::<u32, 4>::from_f64(1.00005, Rounding::Down).unwrap();
+/// assert_eq!(price.val(), 1_0000);
+/// let price = Price::<u32, 4>::from_f64(1.00005, Rounding::Up).unwrap();
+/// assert_eq!(price.val(), 1_0001);
+/// let price = Price::<i32, 4>::from_f64(-1.00005, Rounding::HalfEven).unwrap();
+/// assert_eq!(price.val(), -1_0000);
+///
+/// assert!(Price::<u32, 4>::try_from(f64::NAN).is_err());
+/// assert!(Price::<u32, 4>::try_from(f64::INFINITY).is_err());
+/// assert!(Price::<u32, 4>::try_from(-1.0).is_err());
+/// assert!(Price::<u32, 4>::try_from(1e9).is_err());
+/// assert_eq!(Price::<u32, 4>::try_from(1e-300).unwrap().val(), 0);
+///```
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
+
+    /// Fails for NaN, infinity, and values out of range.
+    pub fn from_f64(val: f64, rounding: Rounding) -> Result<Self, TypeError> {
+
+        let invalid = || TypeError::InvalidPrice(format!("{}", val));
+        if !val.is_finite() {
+            return Err(invalid())
+        }
+
+        // Display for f64 is the shortest representation that round-trips,
+        // and never uses exponent notation.
+        let s = format!("{}", val);
+        let (dollars, cents) = s.split_once('.').unwrap_or((&s, ""));
+        let negative = dollars.starts_with('-');
+        let precision = N as usize;
+
+        // Keep `N` decimal places, and summarize the rest as a fraction
+        // of 100, which is all that `Rounding` needs to know.
+        let kept = format!("{}{:0<precision$.precision$}", dollars, cents);
+        let rest = cents.get(precision..).unwrap_or("");
+        let rest = match rest.trim_end_matches('0') {
+            "" => 0,
+            "5" => 50,
+            rest if rest < "5" => 25,
+            _ => 75,
+        };
+
+        let kept = kept.parse::<i128>().map_err(|_| invalid())?;
+        let kept = kept.checked_mul(100).ok_or_else(invalid)?;
+        let kept = if negative { kept - rest } else { kept + rest };
+        let val = rounding.div(kept, 100);
+        Self::new(I::from(val).ok_or_else(invalid)?).map_err(|_| invalid())
+    }
+
+    /// Nearest `f64` to the price.
+    pub fn to_f64(&self) -> f64 {
+        self.to_string().parse().expect("Decimal notation is a valid float")
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> TryFrom<f64>
+    for Price<I, N>
+{
+    type Error = TypeError;
+
+    /// Rounds to the nearest increment, with ties going to even.
+    fn try_from(val: f64) -> Result<Self, TypeError> {
+        Self::from_f64(val, Rounding::HalfEven)
+    }
+}
+
+
+/// Exact conversion to `Decimal`, with the scale set to `N`.
+/// `N` must be at most 28, the largest scale `Decimal` supports.
+///```
+/// use nsdq_util::Price;
+/// use rust_decimal::Decimal;
+///
+/// let price = Price::<u64, 8>::new(3_5000_0000).unwrap();
+/// let decimal = Decimal::from(price);
+/// assert_eq!(decimal.to_string(), "3.50000000");
+/// assert_eq!(Price::<u64, 8>::try_from(decimal), Ok(price));
+///
+/// let decimal = Decimal::new(35, 1);
+/// assert_eq!(Price::<u32, 4>::try_from(decimal).unwrap().val(), 3_5000);
+/// assert!(Price::<u32, 4>::try_from(Decimal::new(350001, 5)).is_err());
+/// assert!(Price::<u32, 4>::try_from(Decimal::new(-35, 1)).is_err());
+/// assert!(Price::<u128, 40>::try_from(Decimal::ONE).is_err());
+///```
+#[cfg(feature = "rust_decimal")]
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> From<Price<I, N>>
+    for rust_decimal::Decimal
+{
+    fn from(price: Price<I, N>) -> Self {
+        const { assert!(N <= 28, "Decimal supports a scale of at most 28") };
+        rust_decimal::Decimal::from_i128_with_scale(to_i128(price.val), N as u32)
+    }
+}
+
+/// Exact conversion, failing if digits would be lost
+/// or the price is out of range.
+#[cfg(feature = "rust_decimal")]
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8>
+    TryFrom<rust_decimal::Decimal> for Price<I, N>
+{
+    type Error = TypeError;
+
+    fn try_from(decimal: rust_decimal::Decimal) -> Result<Self, TypeError> {
+
+        let invalid = || TypeError::InvalidPrice(decimal.to_string());
+
+        let mantissa = decimal.mantissa();
+        let scale = decimal.scale();
+        let val = match scale.checked_sub(N as u32) {
+            Some(excess) => {
+                let denom = pow10(excess as u8).ok_or_else(invalid)?;
+                if mantissa % denom != 0 {
+                    return Err(invalid())
+                }
+                mantissa / denom
+            },
+            None => pow10(N - scale as u8)
+                .and_then(|scale| mantissa.checked_mul(scale))
+                .ok_or_else(invalid)?,
+        };
+
+        Self::new(I::from(val).ok_or_else(invalid)?).map_err(|_| invalid())
+    }
+}
+
+
+/// How to round a price that falls between increments of its precision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rounding {
+    /// Toward negative infinity.
+    Down,
+    /// Toward positive infinity.
+    Up,
+    /// To the nearest increment, with ties going to the even increment.
+    HalfEven,
+}
+
+impl Rounding {
+
+    /// Divide, rounding the quotient. `den` must be positive.
+    pub(crate) fn div(&self, num: i128, den: i128) -> i128 {
+
+        let floor = num.div_euclid(den);
+        let rem = num.rem_euclid(den);
+        if rem == 0 {
+            return floor
+        }
+
+        match self {
+            Rounding::Down => floor,
+            Rounding::Up => floor + 1,
+            Rounding::HalfEven => match (rem * 2).cmp(&den) {
+                std::cmp::Ordering::Less => floor,
+                std::cmp::Ordering::Greater => floor + 1,
+                std::cmp::Ordering::Equal => floor + (floor & 1),
+            },
+        }
+    }
+}
+
+/// Every integer type used for prices fits in an `i128`.
+pub(crate) fn to_i128<I: num_traits::PrimInt>(val: I) -> i128 {
+    val.to_i128().expect("Price fits in i128")
+}
+
+/// `10^exp`, or `None` if it does not fit in `i128`.
+fn pow10(exp: u8) -> Option<i128> {
+    num_traits::checked_pow(10i128, exp as usize)
+}
+
+
+/// Prints the price in decimal notation with `N` decimal places.
+/// The alternate flag `{:#}` trims trailing zeros from the fraction.
+/// Parsing accepts up to `N` decimal places, an optional sign,
+/// and the dollar sign and thousands separators of `to_dollars`,
+/// so every printed price parses back to itself.
+///```
+/// use nsdq_util::Price;
+///
+/// let price = Price::<u32, 4>::new(3_5000).unwrap();
+/// assert_eq!(price.to_string(), "3.5000");
+/// assert_eq!(format!("{:#}", price), "3.5");
+/// assert_eq!(format!("{:#}", Price::<u64, 8>::new(12_0000_0000).unwrap()), "12");
+///
+/// assert_eq!("3.5".parse::<Price<u32, 4>>(), Ok(price));
+/// assert_eq!("3.50".parse::<Price<u32, 4>>(), Ok(price));
+/// assert_eq!("199999.99".parse(), Price::<u64, 4>::new(199_999_9900));
+///
+/// let offset: Price<i32, 4> = "-0.0100".parse().unwrap();
+/// assert_eq!(offset.val(), -100);
+/// assert_eq!(offset.to_string(), "-0.0100");
+///
+/// assert!("3.50001".parse::<Price<u32, 4>>().is_err());
+/// assert!("500000".parse::<Price<u32, 4>>().is_err());
+/// assert!("-0.0100".parse::<Price<u32, 4>>().is_err());
+/// assert!("3.".parse::<Price<u32, 4>>().is_err());
+///
+/// // Dollar notation, with thousands separators.
+/// let limit = Price::<u64, 4>::new(199_999_9900).unwrap();
+/// assert_eq!(limit.to_dollars(), "$199,999.99");
+/// assert_eq!(price.to_dollars(), "$3.50");
+/// assert_eq!(offset.to_dollars(), "-$0.01");
+/// assert_eq!(Price::<u64, 0>::new(1_500_000).unwrap().to_dollars(), "$1,500,000");
+/// assert_eq!("$199,999.99".parse(), Ok(limit));
+/// assert_eq!("-$0.01".parse(), Ok(offset));
+/// assert!("$1,99,999.99".parse::<Price<u64, 4>>().is_err());
+///```
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::fmt::Display
+    for Price<I, N>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        fmt_decimal(f, to_i128(self.val), N)
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> std::str::FromStr
+    for Price<I, N>
+{
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let invalid = || TypeError::InvalidPrice(String::from(s));
+
+        let (val, decimals) = parse_decimal(s).ok_or_else(invalid)?;
+        if decimals > N {
+            return Err(invalid())
+        }
+        let val = pow10(N - decimals).and_then(|scale| val.checked_mul(scale))
+            .ok_or_else(invalid)?;
+
+        Self::new(I::from(val).ok_or_else(invalid)?).map_err(|_| invalid())
+    }
+}
+
+impl<I: num_traits::PrimInt + std::fmt::Display, const N: u8> Price<I, N> {
+
+    /// Dollar notation with thousands separators, such as `$199,999.99`,
+    /// trimming trailing zeros beyond the cents.
+    pub fn to_dollars(&self) -> String {
+        fmt_dollars(to_i128(self.val), N)
+    }
+}
+
+/// Write a fixed-point value in decimal notation with `precision` places,
+/// trimming trailing zeros for the alternate flag.
+pub(crate) fn fmt_decimal(
+    f: &mut std::fmt::Formatter,
+    val: i128,
+    precision: u8
+) -> std::fmt::Result {
+
+    let denom = 10u128.pow(precision as u32);
+    let sign = if val < 0 { "-" } else { "" };
+    let dollars = val.unsigned_abs() / denom;
+    let cents = val.unsigned_abs() % denom;
+
+    let mut frac = match precision {
+        0 => String::new(),
+        _ => format!("{:0width$}", cents, width = precision as usize),
+    };
+    if f.alternate() {
+        frac.truncate(frac.trim_end_matches('0').len());
+    }
+
+    match frac.is_empty() {
+        true => f.pad(&format!("{}{}", sign, dollars)),
+        false => f.pad(&format!("{}{}.{}", sign, dollars, frac)),
+    }
+}
+
+/// Write a fixed-point value in dollar notation, grouping thousands
+/// and keeping up to two decimal places unless more are significant.
+pub(crate) fn fmt_dollars(val: i128, precision: u8) -> String {
+
+    let denom = 10u128.pow(precision as u32);
+    let sign = if val < 0 { "-" } else { "" };
+    let dollars = (val.unsigned_abs() / denom).to_string();
+    let cents = val.unsigned_abs() % denom;
+
+    let mut grouped = String::new();
+    for (i, digit) in dollars.chars().enumerate() {
+        if i > 0 && (dollars.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let mut frac = match precision {
+        0 => String::new(),
+        _ => format!("{:0width$}", cents, width = precision as usize),
+    };
+    let keep = frac.trim_end_matches('0').len().max(2.min(frac.len()));
+    frac.truncate(keep);
+
+    match frac.is_empty() {
+        true => format!("{}${}", sign, grouped),
+        false => format!("{}${}.{}", sign, grouped, frac),
+    }
+}
+
+/// Parse decimal notation with an optional sign, dollar sign
+/// and thousands separators,
+/// returning the fixed-point value and the number of decimal places.
+pub(crate) fn parse_decimal(s: &str) -> Option<(i128, u8)> {
+
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let unsigned = unsigned.strip_prefix('$').unwrap_or(unsigned);
+    let (dollars, cents) = match unsigned.split_once('.') {
+        Some((dollars, cents)) if !cents.is_empty() => (dollars, cents),
+        Some(_) => return None,
+        None => (unsigned, ""),
+    };
+
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    let mut groups = dollars.split(',');
+    let first = groups.next().unwrap_or_default();
+    let grouped = dollars.contains(',');
+    if first.is_empty() || !is_digits(first) || (grouped && first.len() > 3)
+        || !groups.all(|group| group.len() == 3 && is_digits(group))
+        || !is_digits(cents)
+    {
+        return None
+    }
+    let dollars = dollars.replace(',', "");
+
+    let decimals = u8::try_from(cents.len()).ok()?;
+    let val = format!("{}{}", dollars, cents).parse::<i128>().ok()?;
+    Some((if negative { -val } else { val }, decimals))
 }
 
 
@@ -84,6 +683,14 @@ impl<const N: u8> Price<u32, N> {
     }
 }
 
+impl<const N: u8> Wire for Price<u32, N> {
+    const LEN: usize = 4;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Self::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
+}
+
 
 /// `Price<i32, 4>` is used for setting peg offsets in OUCH.
 ///```
@@ -106,7 +713,7 @@ impl<const N: u8> Price<i32, N> {
         let denom = 10_i32.pow(N as u32);
         let dollars = self.val / denom;
         let cents = self.val % denom;
-        (dollars, cents.abs() as u32)
+        (dollars, cents.unsigned_abs())
     }
 
     /// Encode price as big-endian bytes.
@@ -119,6 +726,41 @@ impl<const N: u8> Price<i32, N> {
         let (input, val) = nom::number::streaming::be_i32(input)?;
         Ok((input, Self { val }))
     }
+
+    /// Magnitude of the price, or `None` if it is out of range.
+    ///```
+    /// use nsdq_util::Price;
+    ///
+    /// let offset = Price::<i32, 4>::new(-100).unwrap();
+    /// assert_eq!(offset.checked_abs(), Price::new(100).ok());
+    /// assert_eq!(offset.abs(), Price::new(100).unwrap());
+    /// assert_eq!(offset.signum(), -1);
+    /// assert!(offset.is_negative());
+    ///```
+    pub fn checked_abs(&self) -> Option<Self> {
+        Self::new(self.val.checked_abs()?).ok()
+    }
+
+    /// Magnitude of the price.
+    ///
+    /// # Panics
+    /// Will panic if the magnitude is out of range.
+    pub fn abs(&self) -> Self {
+        self.checked_abs().expect("Price magnitude is in range")
+    }
+
+    /// -1, 0, or 1, matching the sign of the price.
+    pub fn signum(&self) -> i32 { self.val.signum() }
+
+    pub fn is_negative(&self) -> bool { self.val.is_negative() }
+}
+
+impl<const N: u8> Wire for Price<i32, N> {
+    const LEN: usize = 4;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Self::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
 }
 
 
@@ -161,16 +803,6 @@ impl<const N: u8> Price<u64, N> {
         (dollars, cents)
     }
 
-    /// Use $200,000.0000 to flag an OUCH order as a market order.
-    pub fn market() -> Self {
-        Price { val: 200_000_0000u64 }
-    }
-
-    /// Use $214,748.3647 to flag an OUCH cross order as a market order.
-    pub fn market_cross() -> Self {
-        Price { val: 214_748_3647u64 }
-    }
-
     /// Encode price as big-endian bytes.
     pub fn encode(&self) -> [u8; 8] {
         self.val.to_be_bytes()
@@ -183,3 +815,11 @@ impl<const N: u8> Price<u64, N> {
     }
 }
 
+impl<const N: u8> Wire for Price<u64, N> {
+    const LEN: usize = 8;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { Self::parse(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.encode())
+    }
+}
+
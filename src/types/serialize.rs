@@ -0,0 +1,505 @@
+
+//! Serde support behind the `serde` feature.
+//! Prices are decimal strings, fixed-length strings are trimmed,
+//! and enums are variant names, which may also be deserialized
+//! from their wire codes.
+//!
+//! Binary formats, which are not human-readable, instead carry prices
+//! and timestamps as their raw integers, and enums as their wire codes.
+//! Human-readable formats must be self-describing, such as JSON,
+//! since prices, timestamps and enums accept more than one form there.
+
+/// Emits serde impls for `define_str!` types when `serde` is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export] macro_rules! __serde_str {
+    ($name:ident [$len:expr] $($validate:path)?) => {
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(self.to_str())
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                use $crate::__private::serde::de::Error;
+
+                let s = <String as $crate::__private::serde::Deserialize>
+                    ::deserialize(deserializer)?;
+                $crate::__serde_str!(@validate $name [$len] s $($validate)?)
+                    .map_err(D::Error::custom)
+            }
+        }
+    };
+
+    (@validate $name:ident [$len:expr] $s:ident $validate:path) => {
+        $validate(&$s)
+    };
+
+    (@validate $name:ident [$len:expr] $s:ident) => {
+        match $s.len() <= $len && $s.is_ascii() {
+            true => Ok($name(
+                $crate::types::string::helper::fixed_str::<{ $len }>(&$s)
+            )),
+            false => Err($crate::error::TypeError::InvalidString(
+                String::from(stringify!($name)),
+                $s
+            )),
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export] macro_rules! __serde_str {
+    ($($tokens:tt)*) => {};
+}
+
+/// Emits serde impls for `define_enum!` types when `serde` is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export] macro_rules! __serde_enum {
+    ($form:ident $name:ident; $([$tag:expr] $kind:ident),*
+        $(; $unknown:ident)?
+    ) => {
+
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                if !serializer.is_human_readable() {
+                    return $crate::__private::serde::Serialize::serialize(
+                        &self.encode(),
+                        serializer
+                    )
+                }
+                match self {
+                    $(
+                        $name::$kind => serializer.serialize_str(stringify!($kind)),
+                    )*
+                    $(
+                        $name::$unknown(raw) => $crate::__serde_enum!(
+                            @serialize_raw $form serializer raw
+                        ),
+                    )?
+                }
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                use $crate::__private::serde::de::{ self, Error };
+
+                if !deserializer.is_human_readable() {
+                    let code = <[u8; <$name as $crate::Wire>::LEN] as
+                        $crate::__private::serde::Deserialize>
+                        ::deserialize(deserializer)?;
+                    return $name::parse(&code)
+                        .map(|(_, kind)| kind)
+                        .map_err(|_| D::Error::custom(format!(
+                            "invalid {} code {:?}", stringify!($name), code
+                        )))
+                }
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = $name;
+
+                    fn expecting(
+                        &self,
+                        f: &mut std::fmt::Formatter
+                    ) -> std::fmt::Result {
+                        write!(f, "a {} name or wire code", stringify!($name))
+                    }
+
+                    fn visit_str<E: de::Error>(self, s: &str) -> Result<$name, E> {
+                        $(
+                            if s == stringify!($kind) {
+                                return Ok($name::$kind)
+                            }
+                        )*
+                        $(
+                            if $crate::__serde_enum!(@code $form s $tag) {
+                                return Ok($name::$kind)
+                            }
+                        )*
+                        $(
+                            if let Some(raw) = $crate::__serde_enum!(@raw_code $form s) {
+                                return Ok($name::$unknown(raw))
+                            }
+                        )?
+                        Err(E::invalid_value(de::Unexpected::Str(s), &self))
+                    }
+
+                    fn visit_u64<E: de::Error>(self, v: u64) -> Result<$name, E> {
+                        $(
+                            if $crate::__serde_enum!(@number $form v $tag) {
+                                return Ok($name::$kind)
+                            }
+                        )*
+                        $(
+                            if let Some(raw) = $crate::__serde_enum!(@raw_number $form v) {
+                                return Ok($name::$unknown(raw))
+                            }
+                        )?
+                        Err(E::invalid_value(de::Unexpected::Unsigned(v), &self))
+                    }
+                }
+
+                deserializer.deserialize_any(Visitor)
+            }
+        }
+    };
+
+    (@code char $s:ident $tag:expr) => {{
+        let mut chars = $s.chars();
+        chars.next() == Some($tag) && chars.next().is_none()
+    }};
+    (@code u16 $s:ident $tag:expr) => { false };
+    (@code bytes $s:ident $tag:expr) => { $s.as_bytes() == &$tag[..] };
+
+    (@number u16 $v:ident $tag:expr) => { $v == $tag as u64 };
+    (@number $form:ident $v:ident $tag:expr) => { false };
+
+    // Unknown codes serialize as, and deserialize from, their wire code.
+    (@serialize_raw char $serializer:ident $raw:ident) => {
+        $serializer.serialize_str(&(*$raw as char).to_string())
+    };
+    (@serialize_raw u16 $serializer:ident $raw:ident) => {
+        $serializer.serialize_u16(*$raw)
+    };
+    (@serialize_raw bytes $serializer:ident $raw:ident) => {
+        $serializer.serialize_str(&String::from_utf8_lossy($raw))
+    };
+
+    (@raw_code char $s:ident) => {
+        match $s.as_bytes() {
+            [b] if b.is_ascii() => Some(*b),
+            _ => None,
+        }
+    };
+    (@raw_code u16 $s:ident) => { None };
+    (@raw_code bytes $s:ident) => { $s.as_bytes().try_into().ok() };
+
+    (@raw_number u16 $v:ident) => { u16::try_from($v).ok() };
+    (@raw_number $form:ident $v:ident) => { None };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export] macro_rules! __serde_enum {
+    ($($tokens:tt)*) => {};
+}
+
+
+/// ```
+/// use nsdq_util::{ Mpid, Price, PriceOrMarket, StockSymbol };
+/// use nsdq_util::ouch::*;
+/// use nsdq_util::itch::AddOrder;
+///
+/// let price = Price::<u32, 4>::new(3_5000).unwrap();
+/// assert_eq!(serde_json::to_string(&price).unwrap(), r#""3.5000""#);
+/// assert_eq!(serde_json::from_str::<Price<u32, 4>>(r#""3.5""#).unwrap(), price);
+/// assert_eq!(serde_json::from_str::<Price<u32, 4>>("3.5").unwrap(), price);
+/// assert!(serde_json::from_str::<Price<u32, 4>>(r#""3.50001""#).is_err());
+/// assert!(serde_json::from_str::<Price<u32, 4>>("3.50001").is_err());
+///
+/// let symbol = StockSymbol::from("AAPL").unwrap();
+/// assert_eq!(serde_json::to_string(&symbol).unwrap(), r#""AAPL""#);
+/// assert_eq!(serde_json::from_str::<StockSymbol>(r#""AAPL""#).unwrap(), symbol);
+/// assert!(serde_json::from_str::<Mpid>(r#""firm""#).is_err());
+///
+/// assert_eq!(serde_json::to_string(&Side::Buy).unwrap(), r#""Buy""#);
+/// assert_eq!(serde_json::from_str::<Side>(r#""Buy""#).unwrap(), Side::Buy);
+/// assert_eq!(serde_json::from_str::<Side>(r#""B""#).unwrap(), Side::Buy);
+/// assert_eq!(
+///     serde_json::from_str::<RejectReason>("9").unwrap(),
+///     RejectReason::InvalidSide
+/// );
+/// let unknown = RejectReason::Unknown(0x00FF);
+/// assert_eq!(serde_json::to_string(&unknown).unwrap(), "255");
+/// assert_eq!(serde_json::from_str::<RejectReason>("255").unwrap(), unknown);
+///
+/// let market: PriceOrMarket = serde_json::from_str(r#""MARKET""#).unwrap();
+/// assert_eq!(market, PriceOrMarket::Market);
+///
+/// // Prices are not bound by the OUCH order limit.
+/// let sentinel = Price::<u64, 4>::new(200_000_0000).unwrap();
+/// let json = serde_json::to_string(&sentinel).unwrap();
+/// assert_eq!(serde_json::from_str::<Price<u64, 4>>(&json).unwrap(), sentinel);
+///
+/// let enter = EnterOrder {
+///     user_ref_num: 1,
+///     side: Side::Buy,
+///     quantity: 100,
+///     symbol: StockSymbol::from("AAPL").unwrap(),
+///     price: PriceOrMarket::Market,
+///     time_in_force: TimeInForce::Day,
+///     display: Display::Visible,
+///     capacity: Capacity::Agency,
+///     intermarket_sweep: false,
+///     cross_type: CrossType::ContinuousMarket,
+///     cl_ord_id: ClOrdId::from("ORDER1").unwrap(),
+///     appendage: OptionalAppendage::new(),
+/// };
+/// let json = serde_json::to_string(&enter).unwrap();
+/// assert!(json.contains(r#""price":"MARKET""#));
+/// assert_eq!(serde_json::from_str::<EnterOrder>(&json).unwrap(), enter);
+///
+/// let json = r#"{
+///     "header": {
+///         "stock_locate": 1,
+///         "tracking_number": 2,
+///         "timestamp": "09:30:00"
+///     },
+///     "order_reference_number": 42,
+///     "side": "B",
+///     "shares": 100,
+///     "stock": "AAPL",
+///     "price": "150.01"
+/// }"#;
+/// let order: AddOrder = serde_json::from_str(json).unwrap();
+/// assert_eq!(order.price, Price::new(150_0100).unwrap());
+/// let json = serde_json::to_string(&order).unwrap();
+/// assert_eq!(serde_json::from_str::<AddOrder>(&json).unwrap(), order);
+///
+/// // Binary formats carry raw integers and wire codes.
+/// let bytes = bincode::serialize(&order).unwrap();
+/// assert_eq!(bincode::deserialize::<AddOrder>(&bytes).unwrap(), order);
+/// let bytes = bincode::serialize(&enter).unwrap();
+/// assert_eq!(bincode::deserialize::<EnterOrder>(&bytes).unwrap(), enter);
+/// let bytes = bincode::serialize(&unknown).unwrap();
+/// assert_eq!(bytes, 255u16.to_be_bytes());
+/// assert_eq!(bincode::deserialize::<RejectReason>(&bytes).unwrap(), unknown);
+/// assert!(bincode::deserialize::<Side>(b"Z").is_err());
+/// ```
+#[cfg(feature = "serde")]
+mod impls {
+
+    use std::{ fmt::Display, str::FromStr };
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+        de::Error,
+    };
+
+    use crate::{
+        error::TypeError,
+        types::{
+            price::{ Price, to_i128 },
+            dyn_price::DynPrice,
+            notional::Notional,
+            domain::*,
+            time::Timestamp,
+        },
+    };
+
+    fn serialize_display<T, S>(val: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(val)
+    }
+
+    fn deserialize_from_str<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr<Err = TypeError>,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+    }
+
+    /// Prices may also be deserialized from numbers,
+    /// which must not have more decimal places than the price.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Decimal {
+        Str(String),
+        Float(f64),
+    }
+
+    impl<I, const N: u8> Serialize for Price<I, N>
+    where
+        I: num_traits::PrimInt + Display,
+    {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S
+        ) -> Result<S::Ok, S::Error> {
+            match serializer.is_human_readable() {
+                true => serialize_display(self, serializer),
+                false => serializer.serialize_i128(to_i128(self.val())),
+            }
+        }
+    }
+
+    impl<'de, I, const N: u8> Deserialize<'de> for Price<I, N>
+    where
+        I: num_traits::PrimInt + Display,
+    {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D
+        ) -> Result<Self, D::Error> {
+
+            if !deserializer.is_human_readable() {
+                let val = i128::deserialize(deserializer)?;
+                let invalid = || TypeError::InvalidPrice(val.to_string());
+                return I::from(val).ok_or_else(invalid)
+                    .and_then(|val| Price::new(val).map_err(|_| invalid()))
+                    .map_err(D::Error::custom)
+            }
+
+            // The shortest decimal form of a float parses like a string,
+            // so that excess decimal places fail rather than round.
+            match Decimal::deserialize(deserializer)? {
+                Decimal::Str(s) => s.parse(),
+                Decimal::Float(val) => val.to_string().parse(),
+            }.map_err(D::Error::custom)
+        }
+    }
+
+    /// Decimal strings, with the precision of the string.
+    impl Serialize for DynPrice {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S
+        ) -> Result<S::Ok, S::Error> {
+            serialize_display(self, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for DynPrice {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D
+        ) -> Result<Self, D::Error> {
+            deserialize_from_str(deserializer)
+        }
+    }
+
+    impl<const N: u8> Serialize for Notional<N> {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S
+        ) -> Result<S::Ok, S::Error> {
+            serialize_display(self, serializer)
+        }
+    }
+
+    impl<'de, const N: u8> Deserialize<'de> for Notional<N> {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D
+        ) -> Result<Self, D::Error> {
+            deserialize_from_str(deserializer)
+        }
+    }
+
+    macro_rules! serde_domain {
+        ($($name:ident),*) => { $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(
+                    &self,
+                    serializer: S
+                ) -> Result<S::Ok, S::Error> {
+                    serialize_display(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(
+                    deserializer: D
+                ) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer)
+                }
+            }
+        )* };
+    }
+
+    serde_domain!(OuchOrderPrice, MwcbLevel, PegOffset);
+
+    /// Formatted as `HH:MM:SS.nnnnnnnnn`,
+    /// and also deserialized from nanoseconds since midnight.
+    impl Serialize for Timestamp {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S
+        ) -> Result<S::Ok, S::Error> {
+            match serializer.is_human_readable() {
+                true => serialize_display(self, serializer),
+                false => serializer.serialize_u64(self.nanos()),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Timestamp {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D
+        ) -> Result<Self, D::Error> {
+
+            if !deserializer.is_human_readable() {
+                let nanos = u64::deserialize(deserializer)?;
+                return Timestamp::new(nanos).map_err(D::Error::custom)
+            }
+
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum Repr {
+                Str(String),
+                Nanos(u64),
+            }
+
+            match Repr::deserialize(deserializer)? {
+                Repr::Str(s) => s.parse(),
+                Repr::Nanos(nanos) => Timestamp::new(nanos),
+            }.map_err(D::Error::custom)
+        }
+    }
+
+    /// Limit prices are decimal strings,
+    /// and the sentinels are `"MARKET"` and `"MARKET_CROSS"`.
+    impl Serialize for PriceOrMarket {
+        fn serialize<S: Serializer>(
+            &self,
+            serializer: S
+        ) -> Result<S::Ok, S::Error> {
+            match self {
+                PriceOrMarket::Limit(price) => {
+                    serialize_display(price, serializer)
+                },
+                PriceOrMarket::Market => serializer.serialize_str("MARKET"),
+                PriceOrMarket::MarketCross => {
+                    serializer.serialize_str("MARKET_CROSS")
+                },
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for PriceOrMarket {
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D
+        ) -> Result<Self, D::Error> {
+            match String::deserialize(deserializer)?.as_str() {
+                "MARKET" => Ok(PriceOrMarket::Market),
+                "MARKET_CROSS" => Ok(PriceOrMarket::MarketCross),
+                price => price.parse()
+                    .map(PriceOrMarket::Limit)
+                    .map_err(D::Error::custom),
+            }
+        }
+    }
+}
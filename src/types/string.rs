@@ -15,8 +15,13 @@
 /// ```
 /// NOTE: `new` or `from_str` functions are not included in this macro,
 /// in case there are special constraints on the character types.
+///
+/// With the `serde` feature, the string serializes trimmed.
+/// Deserializing accepts ASCII strings up to the fixed length,
+/// unless a constructor is given to check the special constraints:
+/// `define_str!(Name [4usize] "Doc", validate = Name::from)`.
 #[macro_export] macro_rules! define_str {
-    ($name:ident [$len:expr] $doc:expr) => {
+    ($name:ident [$len:expr] $doc:expr $(, validate = $validate:path)?) => {
 
         #[derive(Debug, Clone, Copy, PartialEq, Eq)]
         #[doc = $doc]
@@ -66,6 +71,20 @@
             }
         }
 
+        impl $crate::Wire for $name {
+            const LEN: usize = $len;
+            fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+                $name::parse(input)
+            }
+            fn encode_into(
+                &self,
+                buf: &mut [u8]
+            ) -> Result<usize, $crate::error::TypeError> {
+                $crate::types::wire::write_bytes(buf, &self.0)
+            }
+        }
+
+        $crate::__serde_str!($name [$len] $($validate)?);
     }
 }
 
@@ -80,7 +99,8 @@ use crate::error::TypeError;
 define_str!{ 
     Mpid [4usize] 
         "Market Participant Identifier (MPID). \
-        Used for identifying firms registered with FINRA." 
+        Used for identifying firms registered with FINRA.",
+        validate = Mpid::from
 }
 
 impl Default for Mpid {
@@ -132,7 +152,8 @@ define_str!{
         website. \n \
         For NYSE-, NYSE American- and NYSE Arca-listed securities with \
         subordinate issue types, please refer to Ticker Symbol Convention page \
-        on the Nasdaq Trader website."
+        on the Nasdaq Trader website.",
+        validate = StockSymbol::from
 }
 
 impl Default for StockSymbol {
@@ -179,6 +200,22 @@ pub mod helper {
         buf
     }
 
+    /// Creates a fixed-length string, padding on the left with spaces.
+    /// (e.g. for SoupBinTCP session names)
+    /// ```
+    /// use nsdq_util::types::string::helper::fixed_str_left;
+    ///
+    /// assert_eq!(&fixed_str_left::<6>("ABC"), b"   ABC");
+    /// assert_eq!(&fixed_str_left::<2>("ABC"), b"AB");
+    /// ```
+    pub fn fixed_str_left<const N: usize>(s: &str) -> [u8; N] {
+        let mut buf = [b' '; N];
+        let chars = s.as_bytes();
+        let copy_len = usize::min(N, chars.len());
+        buf[N - copy_len..].copy_from_slice(&chars[..copy_len]);
+        buf
+    }
+
     /// Checks if all characters are uppercase alpha. (e.g. for Mpid)
     pub fn is_uppercase(s: &str) -> bool {
         s.chars().all(|c| c.is_ascii_uppercase())
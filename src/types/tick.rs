@@ -0,0 +1,177 @@
+
+//! Minimum price increments under Reg NMS Rule 612.
+//! Orders priced at or above $1.00 must be in whole cents,
+//! and orders below $1.00 may be priced to $0.0001.
+
+use crate::{
+    error::TypeError,
+    types::price::{ Price, Rounding, to_i128 },
+};
+
+/// Direction of an order, which decides how it rounds.
+/// OUCH sides convert with `ouch::codes::Side::direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// A purchase.
+    Buy,
+    /// Any sale, including short sales.
+    Sell,
+}
+
+/// Whether a rounded order price should stay behind the market
+/// or cross further into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Aggression {
+    /// Buy orders round down, sell orders round up.
+    Passive,
+    /// Buy orders round up, sell orders round down.
+    Aggressive,
+}
+
+impl Aggression {
+
+    /// Rounding direction for an order in the given direction.
+    pub fn rounding(&self, direction: Direction) -> Rounding {
+        match (self, direction) {
+            (Aggression::Passive, Direction::Buy) => Rounding::Down,
+            (Aggression::Aggressive, Direction::Buy) => Rounding::Up,
+            (Aggression::Passive, Direction::Sell) => Rounding::Up,
+            (Aggression::Aggressive, Direction::Sell) => Rounding::Down,
+        }
+    }
+}
+
+
+/// Minimum increment for the price, in units of the precision `N`:
+/// $0.01 at or above $1.00, and $0.0001 below.
+/// Below $1.00 with fewer than 4 decimal places,
+/// the smallest representable increment is used.
+/// Negative prices, such as peg offsets, use their magnitude.
+/// `None` with fewer than 2 decimal places, which cannot hold a cent.
+/// ```
+/// use nsdq_util::{ Price, types::tick };
+///
+/// let price = Price::<u32, 4>::new(150_0000).unwrap();
+/// assert_eq!(tick::min_increment(price).unwrap().to_string(), "0.0100");
+///
+/// let price = Price::<u32, 4>::new(9999).unwrap();
+/// assert_eq!(tick::min_increment(price).unwrap().to_string(), "0.0001");
+///
+/// let price = Price::<u64, 8>::new(5000_0000).unwrap();
+/// assert_eq!(tick::min_increment(price).unwrap().to_string(), "0.00010000");
+///
+/// assert_eq!(tick::min_increment(Price::<u32, 0>::new(150).unwrap()), None);
+/// ```
+pub fn min_increment<I, const N: u8>(price: Price<I, N>) -> Option<Price<I, N>>
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    if N < 2 {
+        return None
+    }
+    Price::new(I::from(increment(price))?).ok()
+}
+
+/// Whether the price is a multiple of its minimum increment.
+/// ```
+/// use nsdq_util::{ Price, types::tick };
+///
+/// assert!(tick::is_valid("150.01".parse::<Price<u32, 4>>().unwrap()));
+/// assert!(tick::is_valid("0.9999".parse::<Price<u32, 4>>().unwrap()));
+/// assert!(!tick::is_valid("150.0001".parse::<Price<u32, 4>>().unwrap()));
+/// assert!(!tick::is_valid("-1.0050".parse::<Price<i32, 4>>().unwrap()));
+/// ```
+pub fn is_valid<I, const N: u8>(price: Price<I, N>) -> bool
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    to_i128(price.val()) % increment(price) == 0
+}
+
+/// Return the price if it is a multiple of its minimum increment.
+/// ```
+/// use nsdq_util::{ Price, types::tick };
+///
+/// let price: Price<u64, 4> = "1.5050".parse().unwrap();
+/// assert!(tick::validate(price).is_err());
+/// ```
+pub fn validate<I, const N: u8>(price: Price<I, N>) -> Result<Price<I, N>, TypeError>
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    match is_valid(price) {
+        true => Ok(price),
+        false => Err(TypeError::InvalidPrice(price.to_string())),
+    }
+}
+
+/// Round the price to a multiple of its minimum increment.
+/// `Rounding::HalfEven` rounds to the nearest valid price.
+/// ```
+/// use nsdq_util::{ Price, Rounding, types::tick };
+///
+/// let price: Price<u64, 4> = "1.0050".parse().unwrap();
+/// assert_eq!(tick::round(price, Rounding::Down).unwrap().to_string(), "1.0000");
+/// assert_eq!(tick::round(price, Rounding::Up).unwrap().to_string(), "1.0100");
+/// assert_eq!(tick::round(price, Rounding::HalfEven).unwrap().to_string(), "1.0000");
+///
+/// let price: Price<u64, 8> = "0.99999999".parse().unwrap();
+/// assert_eq!(tick::round(price, Rounding::Up).unwrap().to_string(), "1.00000000");
+/// ```
+pub fn round<I, const N: u8>(
+    price: Price<I, N>,
+    rounding: Rounding
+) -> Result<Price<I, N>, TypeError>
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    let increment = increment(price);
+    let val = rounding.div(to_i128(price.val()), increment) * increment;
+    let invalid = || TypeError::InvalidPrice(price.to_string());
+    Price::new(I::from(val).ok_or_else(invalid)?).map_err(|_| invalid())
+}
+
+/// Round an order price to a valid increment,
+/// either away from the market (passive) or toward it (aggressive).
+/// ```
+/// use nsdq_util::Price;
+/// use nsdq_util::types::tick::{ self, Aggression, Direction };
+///
+/// let price: Price<u64, 4> = "20.0050".parse().unwrap();
+///
+/// let bid = tick::round_order(price, Direction::Buy, Aggression::Passive);
+/// assert_eq!(bid.unwrap().to_string(), "20.0000");
+/// let bid = tick::round_order(price, Direction::Buy, Aggression::Aggressive);
+/// assert_eq!(bid.unwrap().to_string(), "20.0100");
+///
+/// let ask = tick::round_order(price, Direction::Sell, Aggression::Passive);
+/// assert_eq!(ask.unwrap().to_string(), "20.0100");
+/// let ask = tick::round_order(price, Direction::Sell, Aggression::Aggressive);
+/// assert_eq!(ask.unwrap().to_string(), "20.0000");
+/// ```
+pub fn round_order<I, const N: u8>(
+    price: Price<I, N>,
+    direction: Direction,
+    aggression: Aggression
+) -> Result<Price<I, N>, TypeError>
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    round(price, aggression.rounding(direction))
+}
+
+
+/// Minimum increment in units of the precision `N`.
+fn increment<I, const N: u8>(price: Price<I, N>) -> i128
+where
+    I: num_traits::PrimInt + std::fmt::Display,
+{
+    let dollar = 10i128.pow(N as u32);
+    let decimals = match to_i128(price.val()).abs() >= dollar {
+        true => 2,
+        false => 4,
+    };
+    10i128.pow((N as u32).saturating_sub(decimals))
+}
@@ -1,5 +1,7 @@
 
-pub use chrono::{ NaiveTime, Timelike };
+pub use chrono::{ NaiveDate, NaiveTime, Timelike };
+#[cfg(feature = "tz")]
+pub use chrono::{ DateTime, Utc };
 use crate::error::TypeError;
 
 use nom::{
@@ -66,6 +68,13 @@ pub fn encode_ouch_time(time: NaiveTime) -> [u8; 8] {
 
 /// Parse from ITCH-encoded timestamp to Rust-friendly type.
 /// Expects to find 6 bytes (not a full u64).
+/// ```
+/// use nsdq_util::{ parse_itch_time, NaiveTime };
+///
+/// let nanosec = 34_200_000_000_001u64;
+/// let (_, time) = parse_itch_time(&nanosec.to_be_bytes()[2..]).unwrap();
+/// assert_eq!(time.unwrap(), NaiveTime::from_hms_nano_opt(9, 30, 0, 1).unwrap());
+/// ```
 pub fn parse_itch_time(
     input: &[u8]
 ) -> nom::IResult<&[u8], Result<NaiveTime, TypeError>> {
@@ -73,8 +82,8 @@ pub fn parse_itch_time(
     let (input, raw) = take(6usize)(input)?;
 
     let mut buf = [0u8; 8];
-    let (_, tail) = buf.split_at_mut(6usize);
-    tail.copy_from_slice(&raw);
+    let (_, tail) = buf.split_at_mut(2usize);
+    tail.copy_from_slice(raw);
     let nanosec = u64::from_be_bytes(buf);
 
     let d = 10u64.pow(9);
@@ -106,8 +115,8 @@ pub fn parse_itch_time_bold(input: &[u8]) -> nom::IResult<&[u8], NaiveTime> {
     let (input, raw) = take(6usize)(input)?;
 
     let mut buf = [0u8; 8];
-    let (_, tail) = buf.split_at_mut(6usize);
-    tail.copy_from_slice(&raw);
+    let (_, tail) = buf.split_at_mut(2usize);
+    tail.copy_from_slice(raw);
     let nanosec = u64::from_be_bytes(buf);
 
     let d = 10u64.pow(9);
@@ -121,5 +130,330 @@ pub fn parse_itch_time_bold(input: &[u8]) -> nom::IResult<&[u8], NaiveTime> {
     Ok((input, time))
 } 
 
-// NOTE: `encode_itch_time` is not necessary.
+/// Encode a timestamp to the 6-byte BE nanoseconds from midnight used by ITCH.
+/// Fails for a leap second, which ITCH cannot represent.
+/// ```
+/// use nsdq_util::{ encode_itch_time, parse_itch_time, NaiveTime };
+///
+/// let time = NaiveTime::from_hms_nano_opt(9, 30, 0, 1).unwrap();
+/// let bytes = encode_itch_time(time).unwrap();
+/// assert_eq!(bytes, 34_200_000_000_001u64.to_be_bytes()[2..]);
+/// assert_eq!(parse_itch_time(&bytes).unwrap().1, Ok(time));
+///
+/// let leap = NaiveTime::from_hms_nano_opt(23, 59, 59, 1_500_000_000).unwrap();
+/// assert!(encode_itch_time(leap).is_err());
+/// let leap = NaiveTime::from_hms_nano_opt(9, 30, 59, 1_500_000_000).unwrap();
+/// assert!(encode_itch_time(leap).is_err());
+/// ```
+pub fn encode_itch_time(time: NaiveTime) -> Result<[u8; 6], TypeError> {
+
+    let nanosec = u64::from_be_bytes(encode_ouch_time(time));
+    if time.nanosecond() >= 1_000_000_000 {
+        return Err(TypeError::InvalidTime(nanosec))
+    }
+    encode_itch_nanos(nanosec)
+}
+
+/// Encode nanoseconds from midnight to the 6 bytes used by ITCH.
+/// Fails for a day or more, which also covers values over 48 bits.
+/// Use `Timestamp::encode_itch` to keep values of a day or more.
+/// ```
+/// use nsdq_util::{ encode_itch_nanos, Timestamp };
+///
+/// assert_eq!(encode_itch_nanos(1).unwrap(), [0, 0, 0, 0, 0, 1]);
+/// assert!(encode_itch_nanos(Timestamp::DAY - 1).is_ok());
+/// assert!(encode_itch_nanos(Timestamp::DAY).is_err());
+/// assert!(encode_itch_nanos(1 << 48).is_err());
+/// ```
+pub fn encode_itch_nanos(nanosec: u64) -> Result<[u8; 6], TypeError> {
+
+    if nanosec >= Timestamp::DAY {
+        return Err(TypeError::InvalidTime(nanosec))
+    }
+    Timestamp::new(nanosec).map(|time| time.encode_itch())
+}
+
+
+
+/// Nanoseconds since midnight, as sent by both ITCH (6 bytes)
+/// and OUCH (8 bytes), keeping the original integer.
+/// Values are limited to the 48 bits that ITCH can carry,
+/// which is more than a day.
+/// ```
+/// use std::time::Duration;
+/// use nsdq_util::{ Timestamp, NaiveTime };
+///
+/// let nanos = 34_200_000_000_001u64;
+/// let (_, itch) = Timestamp::parse_itch(&nanos.to_be_bytes()[2..]).unwrap();
+/// let (_, ouch) = Timestamp::parse_ouch(&nanos.to_be_bytes()).unwrap();
+/// assert_eq!(itch, ouch);
+/// assert_eq!(itch.nanos(), nanos);
+/// assert_eq!(itch.encode_itch(), nanos.to_be_bytes()[2..]);
+/// assert_eq!(itch.encode_ouch(), nanos.to_be_bytes());
+///
+/// let open = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+/// assert_eq!(itch.to_naive_time(), Some(open + chrono::Duration::nanoseconds(1)));
+/// assert!(itch > Timestamp::from(open));
+/// assert_eq!(itch - Timestamp::from(open), Duration::from_nanos(1));
+/// assert_eq!(itch.to_string(), "09:30:00.000000001");
+/// assert_eq!("09:30:00.000000001".parse(), Ok(itch));
+///
+/// let later = itch + Duration::from_secs(60);
+/// assert_eq!(later.to_string(), "09:31:00.000000001");
+/// assert_eq!(later.duration_since(itch), Some(Duration::from_secs(60)));
+/// assert_eq!(itch.duration_since(later), None);
+///
+/// assert!(Timestamp::new(1 << 48).is_err());
+/// assert!(Timestamp::parse_ouch(&(1u64 << 48).to_be_bytes()).is_err());
+/// assert_eq!(Timestamp::MAX.checked_add(Duration::from_nanos(1)), None);
+/// assert_eq!(Timestamp::new(Timestamp::DAY).unwrap().to_naive_time(), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp(u64);
+
+impl Timestamp {
+
+    /// Nanoseconds in a day.
+    pub const DAY: u64 = 86_400 * 1_000_000_000;
+
+    /// Latest timestamp that fits in the 6 bytes used by ITCH.
+    pub const MAX: Timestamp = Timestamp((1 << 48) - 1);
+
+    /// Midnight.
+    pub const MIDNIGHT: Timestamp = Timestamp(0);
+
+    /// Fails if the value does not fit in 48 bits.
+    pub fn new(nanos: u64) -> Result<Self, TypeError> {
+        match nanos <= Self::MAX.0 {
+            true => Ok(Self(nanos)),
+            false => Err(TypeError::InvalidTime(nanos)),
+        }
+    }
+
+    /// Nanoseconds since midnight.
+    pub fn nanos(&self) -> u64 { self.0 }
+
+    /// Time of day, or `None` if the timestamp is a day or more.
+    pub fn to_naive_time(&self) -> Option<NaiveTime> {
+        let d = 10u64.pow(9);
+        NaiveTime::from_num_seconds_from_midnight_opt(
+            (self.0 / d) as u32,
+            (self.0 % d) as u32
+        ).filter(|_| self.0 < Self::DAY)
+    }
+
+    /// Parse from the 6 bytes used by ITCH.
+    pub fn parse_itch(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (input, raw) = nom::bytes::streaming::take(6usize)(input)?;
+        let mut buf = [0u8; 8];
+        buf[2..].copy_from_slice(raw);
+        Ok((input, Self(u64::from_be_bytes(buf))))
+    }
+
+    /// Parse from the 8 bytes used by OUCH,
+    /// failing if the value does not fit in 48 bits.
+    pub fn parse_ouch(input: &[u8]) -> nom::IResult<&[u8], Self> {
+
+        let (rest, nanos) = be_u64(input)?;
+        match Self::new(nanos) {
+            Ok(time) => Ok((rest, time)),
+            Err(_) => Err(nom::Err::Error(
+                nom::error::Error::new(input, nom::error::ErrorKind::Verify)
+            )),
+        }
+    }
+
+    /// Encode as the 6 bytes used by ITCH.
+    pub fn encode_itch(&self) -> [u8; 6] {
+        let mut buf = [0u8; 6];
+        buf.copy_from_slice(&self.0.to_be_bytes()[2..]);
+        buf
+    }
+
+    /// Encode as the 8 bytes used by OUCH.
+    pub fn encode_ouch(&self) -> [u8; 8] { self.0.to_be_bytes() }
+
+    pub fn checked_add(self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        Self::new(self.0.checked_add(nanos)?).ok()
+    }
+
+    pub fn checked_sub(self, duration: Duration) -> Option<Self> {
+        let nanos = u64::try_from(duration.as_nanos()).ok()?;
+        self.0.checked_sub(nanos).map(Self)
+    }
+
+    /// Time elapsed since an earlier timestamp,
+    /// or `None` if it is later than this one.
+    pub fn duration_since(&self, earlier: Timestamp) -> Option<Duration> {
+        self.0.checked_sub(earlier.0).map(Duration::from_nanos)
+    }
+}
+
+use std::time::Duration;
+
+impl From<NaiveTime> for Timestamp {
+    fn from(time: NaiveTime) -> Self {
+        let secs = time.num_seconds_from_midnight() as u64;
+        let nano = time.nanosecond() as u64;
+        Self(secs * 10u64.pow(9) + nano)
+    }
+}
 
+impl std::ops::Add<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    /// Will panic if the result does not fit in 48 bits.
+    fn add(self, duration: Duration) -> Self {
+        self.checked_add(duration).expect("Timestamp is in range")
+    }
+}
+
+impl std::ops::Sub<Duration> for Timestamp {
+    type Output = Self;
+
+    /// # Panics
+    /// Will panic if the result is before midnight.
+    fn sub(self, duration: Duration) -> Self {
+        self.checked_sub(duration).expect("Timestamp is after midnight")
+    }
+}
+
+impl std::ops::Sub for Timestamp {
+    type Output = Duration;
+
+    /// # Panics
+    /// Will panic if `earlier` is later than this timestamp.
+    fn sub(self, earlier: Timestamp) -> Duration {
+        self.duration_since(earlier).expect("Timestamp is not earlier")
+    }
+}
+
+/// Formats as `HH:MM:SS.nnnnnnnnn`, with hours past 23 for a day or more.
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let secs = self.0 / 10u64.pow(9);
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:09}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60,
+            self.0 % 10u64.pow(9)
+        )
+    }
+}
+
+/// Parses `HH:MM:SS`, with up to 9 decimal places for the seconds.
+impl std::str::FromStr for Timestamp {
+    type Err = TypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+
+        let invalid = || {
+            TypeError::InvalidString(String::from("Timestamp"), String::from(s))
+        };
+        let number = |part: &str| match part.bytes().all(|b| b.is_ascii_digit()) {
+            true if !part.is_empty() => part.parse::<u64>().ok(),
+            _ => None,
+        };
+
+        let (time, frac) = s.split_once('.').unwrap_or((s, "0"));
+        let mut parts = time.split(':');
+        let (Some(h), Some(m), Some(sec), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid())
+        };
+
+        let (h, m, sec) = (
+            number(h).ok_or_else(invalid)?,
+            number(m).filter(|m| *m < 60).ok_or_else(invalid)?,
+            number(sec).filter(|sec| *sec < 60).ok_or_else(invalid)?,
+        );
+        if frac.len() > 9 {
+            return Err(invalid())
+        }
+        let frac = number(frac).ok_or_else(invalid)?
+            * 10u64.pow(9 - frac.len() as u32);
+
+        h.checked_mul(3600)
+            .and_then(|secs| secs.checked_add(m * 60 + sec))
+            .and_then(|secs| secs.checked_mul(10u64.pow(9)))
+            .and_then(|nanos| nanos.checked_add(frac))
+            .and_then(|nanos| Self::new(nanos).ok())
+            .ok_or_else(invalid)
+    }
+}
+
+
+/// US Eastern time, the time zone of Nasdaq timestamps.
+#[cfg(feature = "tz")]
+pub const EASTERN: chrono_tz::Tz = chrono_tz::America::New_York;
+
+/// Conversion to absolute instants, with the `tz` feature.
+/// Nasdaq timestamps count the time elapsed since midnight Eastern,
+/// so they remain unambiguous across DST transitions:
+/// on the day clocks spring forward there is no 02:30 on the wall,
+/// and on the day they fall back 01:30 happens twice,
+/// but each elapsed time is a single instant.
+/// ```
+/// use nsdq_util::{ NaiveDate, NaiveTime, Timestamp };
+///
+/// let at = |h, m| Timestamp::from(NaiveTime::from_hms_opt(h, m, 0).unwrap());
+/// let utc = |date: NaiveDate, h, m| date.and_hms_opt(h, m, 0).unwrap().and_utc();
+///
+/// let summer = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+/// assert_eq!(at(9, 30).to_utc(summer), utc(summer, 13, 30));
+/// let winter = NaiveDate::from_ymd_opt(2025, 12, 1).unwrap();
+/// assert_eq!(at(9, 30).to_utc(winter), utc(winter, 14, 30));
+///
+/// // Clocks spring forward at 02:00 EST, so 2.5 hours after midnight is 03:30 EDT.
+/// let spring = NaiveDate::from_ymd_opt(2025, 3, 9).unwrap();
+/// assert_eq!(at(2, 30).to_utc(spring), utc(spring, 7, 30));
+/// assert_eq!(at(2, 30).to_eastern(spring).to_string(), "2025-03-09 03:30:00 EDT");
+///
+/// // Clocks fall back at 02:00 EDT, so 01:30 on the wall happens twice.
+/// let fall = NaiveDate::from_ymd_opt(2025, 11, 2).unwrap();
+/// assert_eq!(at(1, 30).to_eastern(fall).to_string(), "2025-11-02 01:30:00 EDT");
+/// assert_eq!(at(2, 30).to_eastern(fall).to_string(), "2025-11-02 01:30:00 EST");
+///
+/// assert_eq!(Timestamp::from_utc(utc(fall, 6, 30)), (fall, at(2, 30)));
+/// assert_eq!(Timestamp::from_utc(utc(spring, 7, 30)), (spring, at(2, 30)));
+/// assert_eq!(Timestamp::from_utc(utc(summer, 3, 0)), (summer.pred_opt().unwrap(), at(23, 0)));
+/// ```
+#[cfg(feature = "tz")]
+impl Timestamp {
+
+    /// Instant of the timestamp on the trading date.
+    pub fn to_utc(&self, date: NaiveDate) -> DateTime<Utc> {
+        self.to_eastern(date).with_timezone(&Utc)
+    }
+
+    /// Instant of the timestamp on the trading date, in Eastern time.
+    pub fn to_eastern(&self, date: NaiveDate) -> DateTime<chrono_tz::Tz> {
+        midnight(date) + chrono::Duration::nanoseconds(self.0 as i64)
+    }
+
+    /// Trading date and timestamp of an instant.
+    pub fn from_utc(instant: DateTime<Utc>) -> (NaiveDate, Timestamp) {
+
+        let date = instant.with_timezone(&EASTERN).date_naive();
+        let elapsed = instant.with_timezone(&EASTERN) - midnight(date);
+        let nanos = elapsed.num_nanoseconds()
+            .expect("Less than a day and an hour since midnight");
+        (date, Timestamp(nanos as u64))
+    }
+}
+
+/// Midnight Eastern, which is never skipped or repeated,
+/// because DST transitions happen at 02:00.
+#[cfg(feature = "tz")]
+fn midnight(date: NaiveDate) -> DateTime<chrono_tz::Tz> {
+    use chrono::TimeZone;
+    EASTERN.from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .expect("Midnight exists in Eastern time")
+}
@@ -0,0 +1,208 @@
+
+use nom::number::streaming::{ be_u8, be_u16, be_u32, be_u64 };
+
+use crate::{
+    error::TypeError,
+    parse_bool,
+    encode_bool,
+    parse_ternary,
+    encode_ternary,
+};
+
+
+/// Common interface for fixed-width protocol fields,
+/// so that message types can be generic over their fields
+/// and compute their sizes at compile time.
+///
+/// Implemented for `Price`, integers, booleans (`'Y'`/`'N'`),
+/// ternaries (`'Y'`/`'N'`/`' '`), and every type generated by
+/// `define_str!` and `define_enum!`.
+/// Timestamps are not included, because their width depends on the protocol.
+/// ```
+/// use nsdq_util::{ Wire, Price, StockSymbol };
+///
+/// fn roundtrip<T: Wire + PartialEq + std::fmt::Debug>(val: T) {
+///     let mut buf = vec![0u8; T::LEN];
+///     assert_eq!(val.encode_into(&mut buf).unwrap(), T::LEN);
+///     assert!(val.encode_into(&mut buf[1..]).is_err());
+///     let (rest, parsed) = T::parse(&buf).unwrap();
+///     assert!(rest.is_empty());
+///     assert_eq!(parsed, val);
+/// }
+///
+/// roundtrip(Price::<u32, 4>::new(35000).unwrap());
+/// roundtrip(StockSymbol::from("AAPL").unwrap());
+/// roundtrip(Some(true));
+/// roundtrip(42u16);
+///
+/// const FIELDS: usize = <Price<u64, 4> as Wire>::LEN + <StockSymbol as Wire>::LEN;
+/// assert_eq!(FIELDS, 16);
+/// ```
+pub trait Wire: Sized {
+
+    /// Number of bytes the field occupies.
+    const LEN: usize;
+
+    /// Parse the field from the front of the input.
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self>;
+
+    /// Write the field into the first `LEN` bytes of the buffer,
+    /// returning the number of bytes written.
+    /// Fails without writing if the buffer is shorter than `LEN`.
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError>;
+}
+
+
+impl Wire for u8 {
+    const LEN: usize = 1;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { be_u8(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &[*self])
+    }
+}
+
+impl Wire for u16 {
+    const LEN: usize = 2;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { be_u16(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.to_be_bytes())
+    }
+}
+
+impl Wire for u32 {
+    const LEN: usize = 4;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { be_u32(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.to_be_bytes())
+    }
+}
+
+impl Wire for u64 {
+    const LEN: usize = 8;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { be_u64(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &self.to_be_bytes())
+    }
+}
+
+/// Uses the standard character mapping of 'Y' and 'N'.
+impl Wire for bool {
+    const LEN: usize = 1;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> { parse_bool(input) }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &encode_bool(*self))
+    }
+}
+
+/// Uses the standard ternary mapping of 'Y', 'N', and ' '.
+impl Wire for Option<bool> {
+    const LEN: usize = 1;
+    fn parse(input: &[u8]) -> nom::IResult<&[u8], Self> {
+        parse_ternary(input)
+    }
+    fn encode_into(&self, buf: &mut [u8]) -> Result<usize, TypeError> {
+        write_bytes(buf, &encode_ternary(*self))
+    }
+}
+
+
+/// Copy the bytes to the start of the buffer, returning how many were written.
+/// Fails without writing if the buffer is too short.
+/// ```
+/// use nsdq_util::types::wire::write_bytes;
+///
+/// let mut buf = [0u8; 4];
+/// assert_eq!(write_bytes(&mut buf, b"AB").unwrap(), 2);
+/// assert_eq!(&buf, b"AB\0\0");
+/// assert!(write_bytes(&mut buf, b"ABCDE").is_err());
+/// ```
+pub fn write_bytes(buf: &mut [u8], bytes: &[u8]) -> Result<usize, TypeError> {
+
+    let len = bytes.len();
+    match buf.get_mut(..len) {
+        Some(dest) => {
+            dest.copy_from_slice(bytes);
+            Ok(len)
+        },
+        None => Err(TypeError::BufferTooShort(len, buf.len())),
+    }
+}
+
+
+/// Cursor for serializing consecutive fields into a caller-owned buffer,
+/// such as a reusable send buffer, without allocating.
+/// ```
+/// use nsdq_util::{ Writer, Price, StockSymbol };
+///
+/// let mut buf = [0u8; 64];
+/// let mut writer = Writer::new(&mut buf);
+/// writer.put(&b'O').unwrap();
+/// writer.put(&StockSymbol::from("AAPL").unwrap()).unwrap();
+/// writer.put(&Price::<u32, 4>::new(150_0000).unwrap()).unwrap();
+/// writer.put_slice(b"YN").unwrap();
+///
+/// assert_eq!(writer.position(), 15);
+/// assert_eq!(writer.remaining(), 49);
+/// assert_eq!(&writer.written()[..9], b"OAAPL    ");
+///
+/// let mut small = [0u8; 4];
+/// let mut writer = Writer::new(&mut small);
+/// assert!(writer.put(&StockSymbol::from("AAPL").unwrap()).is_err());
+/// assert_eq!(writer.position(), 0);
+/// ```
+#[derive(Debug)]
+pub struct Writer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> Writer<'a> {
+
+    /// Start writing at the beginning of the buffer.
+    pub fn new(buf: &'a mut [u8]) -> Self { Self { buf, pos: 0 } }
+
+    /// Number of bytes written so far.
+    pub fn position(&self) -> usize { self.pos }
+
+    /// Number of bytes left in the buffer.
+    pub fn remaining(&self) -> usize { self.buf.len() - self.pos }
+
+    /// The bytes written so far.
+    pub fn written(&self) -> &[u8] { &self.buf[..self.pos] }
+
+    /// Write a field and advance past it.
+    pub fn put<T: Wire>(&mut self, val: &T) -> Result<(), TypeError> {
+        self.put_with(|buf| val.encode_into(buf))
+    }
+
+    /// Write raw bytes and advance past them.
+    pub fn put_slice(&mut self, bytes: &[u8]) -> Result<(), TypeError> {
+        self.put_with(|buf| write_bytes(buf, bytes))
+    }
+
+    /// Write with an encoder that reports how many bytes it wrote,
+    /// for variable-length data such as the OUCH optional appendage.
+    pub fn put_with(
+        &mut self,
+        encode: impl FnOnce(&mut [u8]) -> Result<usize, TypeError>
+    ) -> Result<(), TypeError> {
+
+        self.pos += encode(&mut self.buf[self.pos..])?;
+        Ok(())
+    }
+}
+
+/// Treat a request for more data as an error,
+/// for parsing `input` that has already been framed in full.
+pub(crate) fn complete<'a, T>(
+    input: &'a [u8],
+    result: nom::IResult<&'a [u8], T>
+) -> nom::IResult<&'a [u8], T> {
+
+    match result {
+        Err(nom::Err::Incomplete(_)) => Err(nom::Err::Error(
+            nom::error::Error::new(input, nom::error::ErrorKind::Eof)
+        )),
+        result => result,
+    }
+}